@@ -0,0 +1,33 @@
+//! Print a summary of a paper trading account.
+//!
+//! Reads the API key pair from the `APCA_API_KEY_ID` and `APCA_API_SECRET_KEY` environment
+//! variables.
+
+use std::env;
+use std::error::Error;
+use alpaca_kit::account::TradingAccount;
+use alpaca_kit::asset::Asset;
+use alpaca_kit::models;
+use alpaca_kit::{Alpaca, Environment, Query};
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let client = Alpaca::for_environment(
+        Environment::Paper,
+        env::var("APCA_API_KEY_ID")?,
+        env::var("APCA_API_SECRET_KEY")?,
+    )?;
+
+    let account: models::Account = TradingAccount::builder().build()?.query(&client)?;
+    println!("account {} ({})", account.account_number, account.status);
+    println!("cash: {} {}", account.cash, account.currency);
+    println!("equity: {}, buying power: {}", account.equity, account.buying_power);
+
+    let asset = Asset::builder().symbol_or_asset_id("AAPL".into()).build();
+    let asset: models::Asset = asset.query(&client)?;
+    println!(
+        "{} ({}): tradable {}, fractionable {}",
+        asset.symbol, asset.name, asset.tradable, asset.fractionable,
+    );
+
+    Ok(())
+}
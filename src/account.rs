@@ -1,7 +1,21 @@
 use std::borrow::Cow;
+use std::fmt;
+use chrono::{DateTime, NaiveDate, Utc};
 use derive_builder::Builder;
 use http::Method;
+use rust_decimal::Decimal;
+use serde::Serialize;
+use crate::common::{is_set, SortOrder};
 use crate::endpoint::Endpoint;
+use crate::error::BodyError;
+use crate::models::{ActivityType, DayTradeCheck, TradeConfirmEmail};
+use crate::paged::{PageTokens, Pageable};
+use crate::params::{ParamValue, QueryParams};
+
+/// The maximum (and default) number of activities Alpaca returns per page.
+const MAX_ACTIVITIES_PAGE_SIZE: u64 = 100;
+/// The highest options trading level.
+const MAX_OPTIONS_TRADING_LEVEL: u8 = 3;
 
 /// Query information about the API calling user.
 #[derive(Debug, Clone, Copy, Builder)]
@@ -25,3 +39,605 @@ impl Endpoint for TradingAccount {
         "account".into()
     }
 }
+
+/// Query for account activities, optionally of a single type.
+///
+/// Returns a list of `models::Activity`; when paged, each item is a `models::Activity`.
+#[derive(Debug, Clone, Builder)]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
+pub struct AccountActivities {
+    /// Only return activities of this type.
+    #[builder(default)]
+    activity_type: Option<ActivityType>,
+    /// Only return activities on this date.
+    #[builder(default)]
+    date: Option<NaiveDate>,
+    /// Only return activities before this time.
+    #[builder(default)]
+    until: Option<DateTime<Utc>>,
+    /// Only return activities after this time.
+    #[builder(default)]
+    after: Option<DateTime<Utc>>,
+    /// The order of the activities by time.
+    #[builder(default)]
+    direction: Option<SortOrder>,
+    /// The maximum number of activities per page.
+    #[builder(default)]
+    page_size: Option<u64>,
+    /// The ID of the activity to continue after.
+    #[builder(setter(into), default)]
+    page_token: Option<String>,
+}
+
+impl AccountActivities {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> AccountActivitiesBuilder {
+        AccountActivitiesBuilder::default()
+    }
+}
+
+impl AccountActivitiesBuilder {
+    fn validate(&self) -> Result<(), String> {
+        let date = self.date.flatten();
+        if date.is_some() && (self.until.flatten().is_some() || self.after.flatten().is_some()) {
+            return Err("`date` cannot be combined with `until` or `after`".into());
+        }
+        if let Some(Some(page_size)) = self.page_size {
+            if !(1..=MAX_ACTIVITIES_PAGE_SIZE).contains(&page_size) {
+                return Err(format!(
+                    "`page_size` must be between 1 and {}",
+                    MAX_ACTIVITIES_PAGE_SIZE,
+                ));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Endpoint for AccountActivities {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        match &self.activity_type {
+            Some(activity_type) => format!("account/activities/{}", activity_type).into(),
+            None => "account/activities".into(),
+        }
+    }
+
+    fn parameters(&self) -> QueryParams<'_> {
+        let mut params = QueryParams::default();
+
+        params
+            .push_opt("date", self.date)
+            .push_opt("until", self.until)
+            .push_opt("after", self.after)
+            .push_opt("direction", self.direction)
+            .push_opt("page_size", self.page_size)
+            .push_opt("page_token", self.page_token.as_ref());
+
+        params
+    }
+}
+
+impl Pageable for AccountActivities {
+    fn items_field(&self) -> &'static str {
+        // The response is a bare array of activities.
+        "activities"
+    }
+
+    fn page_tokens(&self) -> PageTokens {
+        PageTokens::LastItemId {
+            page_size: self.page_size.unwrap_or(MAX_ACTIVITIES_PAGE_SIZE) as usize,
+        }
+    }
+}
+
+/// The unit of a portfolio history period.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum PeriodUnit {
+    /// Days.
+    Day,
+    /// Weeks.
+    Week,
+    /// Months.
+    Month,
+    /// Years.
+    Year,
+}
+
+impl PeriodUnit {
+    /// The unit as used in a period parameter.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            PeriodUnit::Day => "D",
+            PeriodUnit::Week => "W",
+            PeriodUnit::Month => "M",
+            PeriodUnit::Year => "A",
+        }
+    }
+}
+
+/// The length of a portfolio history, such as `1M`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Period {
+    /// The number of units.
+    pub amount: u32,
+    /// The unit of the period.
+    pub unit: PeriodUnit,
+}
+
+impl Period {
+    /// Create a period.
+    pub const fn new(amount: u32, unit: PeriodUnit) -> Self {
+        Period {
+            amount,
+            unit,
+        }
+    }
+}
+
+impl fmt::Display for Period {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}{}", self.amount, self.unit.as_str())
+    }
+}
+
+impl ParamValue<'static> for Period {
+    fn as_value(&self) -> Cow<'static, str> {
+        self.to_string().into()
+    }
+}
+
+/// The time between points of a portfolio history.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum HistoryTimeFrame {
+    /// One minute.
+    OneMinute,
+    /// Five minutes.
+    FiveMinutes,
+    /// Fifteen minutes.
+    FifteenMinutes,
+    /// One hour.
+    OneHour,
+    /// One day.
+    OneDay,
+}
+
+impl HistoryTimeFrame {
+    /// The time frame as a query parameter.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            HistoryTimeFrame::OneMinute => "1Min",
+            HistoryTimeFrame::FiveMinutes => "5Min",
+            HistoryTimeFrame::FifteenMinutes => "15Min",
+            HistoryTimeFrame::OneHour => "1H",
+            HistoryTimeFrame::OneDay => "1D",
+        }
+    }
+}
+
+impl ParamValue<'static> for HistoryTimeFrame {
+    fn as_value(&self) -> Cow<'static, str> {
+        self.as_str().into()
+    }
+}
+
+/// Which hours intraday portfolio history covers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum IntradayReporting {
+    /// Regular market hours only.
+    MarketHours,
+    /// Regular and extended market hours.
+    ExtendedHours,
+    /// All hours, for assets which trade around the clock.
+    Continuous,
+}
+
+impl IntradayReporting {
+    /// The reporting mode as a query parameter.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            IntradayReporting::MarketHours => "market_hours",
+            IntradayReporting::ExtendedHours => "extended_hours",
+            IntradayReporting::Continuous => "continuous",
+        }
+    }
+}
+
+impl ParamValue<'static> for IntradayReporting {
+    fn as_value(&self) -> Cow<'static, str> {
+        self.as_str().into()
+    }
+}
+
+/// When the profit or loss of a portfolio history is reset.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum PnlReset {
+    /// Reset at the start of each day.
+    PerDay,
+    /// Never reset within the history.
+    NoReset,
+}
+
+impl PnlReset {
+    /// The reset mode as a query parameter.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            PnlReset::PerDay => "per_day",
+            PnlReset::NoReset => "no_reset",
+        }
+    }
+}
+
+impl ParamValue<'static> for PnlReset {
+    fn as_value(&self) -> Cow<'static, str> {
+        self.as_str().into()
+    }
+}
+
+/// Query for the history of the account value.
+///
+/// Returns a `models::PortfolioHistory`. At most two of `period`, `start` and `end` may be given.
+#[derive(Debug, Clone, Copy, Builder)]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
+pub struct PortfolioHistory {
+    /// The length of the history.
+    #[builder(default)]
+    period: Option<Period>,
+    /// The time between points.
+    #[builder(default)]
+    timeframe: Option<HistoryTimeFrame>,
+    /// Which hours intraday histories cover.
+    #[builder(default)]
+    intraday_reporting: Option<IntradayReporting>,
+    /// The start of the history.
+    #[builder(default)]
+    start: Option<DateTime<Utc>>,
+    /// The end of the history.
+    #[builder(default)]
+    end: Option<DateTime<Utc>>,
+    /// When the profit or loss is reset.
+    #[builder(default)]
+    pnl_reset: Option<PnlReset>,
+    /// Include extended hours in intraday histories.
+    #[builder(default)]
+    extended_hours: Option<bool>,
+}
+
+impl PortfolioHistory {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> PortfolioHistoryBuilder {
+        PortfolioHistoryBuilder::default()
+    }
+}
+
+impl PortfolioHistoryBuilder {
+    fn validate(&self) -> Result<(), String> {
+        if let Some(Some(period)) = self.period {
+            if period.amount == 0 {
+                return Err("`period` must be at least one unit long".into());
+            }
+            if self.start.flatten().is_some() && self.end.flatten().is_some() {
+                return Err("`period` cannot be combined with both `start` and `end`".into());
+            }
+        }
+        if let (Some(Some(start)), Some(Some(end))) = (self.start, self.end) {
+            if start > end {
+                return Err("`start` must not be after `end`".into());
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Endpoint for PortfolioHistory {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "account/portfolio/history".into()
+    }
+
+    fn parameters(&self) -> QueryParams<'_> {
+        let mut params = QueryParams::default();
+
+        params
+            .push_opt("period", self.period)
+            .push_opt("timeframe", self.timeframe)
+            .push_opt("intraday_reporting", self.intraday_reporting)
+            .push_opt("start", self.start)
+            .push_opt("end", self.end)
+            .push_opt("pnl_reset", self.pnl_reset)
+            .push_opt("extended_hours", self.extended_hours);
+
+        params
+    }
+}
+
+/// Query for the configuration of the account.
+///
+/// Returns a `models::AccountConfigurations`.
+#[derive(Debug, Clone, Copy, Builder)]
+pub struct AccountConfigurations {}
+
+impl AccountConfigurations {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> AccountConfigurationsBuilder {
+        AccountConfigurationsBuilder::default()
+    }
+}
+
+impl Endpoint for AccountConfigurations {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "account/configurations".into()
+    }
+}
+
+/// Update the configuration of the account.
+///
+/// Only the fields which are set are changed. Returns the updated
+/// `models::AccountConfigurations`.
+#[derive(Debug, Clone, Builder, Serialize)]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
+pub struct UpdateAccountConfigurations {
+    /// When the day trading buying power check is applied.
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    dtbp_check: Option<DayTradeCheck>,
+    /// Which trade confirmations are emailed.
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    trade_confirm_email: Option<TradeConfirmEmail>,
+    /// Whether new orders are blocked.
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    suspend_trade: Option<bool>,
+    /// Whether short selling is disabled.
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    no_shorting: Option<bool>,
+    /// Whether fractional trading is enabled.
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    fractional_trading: Option<bool>,
+    /// The maximum margin multiplier (1, 2 or 4).
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    max_margin_multiplier: Option<Decimal>,
+    /// The maximum options trading level (0 to 3).
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    max_options_trading_level: Option<u8>,
+    /// When the pattern day trader check is applied.
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pdt_check: Option<DayTradeCheck>,
+}
+
+impl UpdateAccountConfigurations {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> UpdateAccountConfigurationsBuilder {
+        UpdateAccountConfigurationsBuilder::default()
+    }
+}
+
+impl UpdateAccountConfigurationsBuilder {
+    fn validate(&self) -> Result<(), String> {
+        let has_change = is_set(&self.dtbp_check)
+            || is_set(&self.trade_confirm_email)
+            || is_set(&self.suspend_trade)
+            || is_set(&self.no_shorting)
+            || is_set(&self.fractional_trading)
+            || is_set(&self.max_margin_multiplier)
+            || is_set(&self.max_options_trading_level)
+            || is_set(&self.pdt_check);
+        if !has_change {
+            return Err("at least one configuration must be updated".into());
+        }
+
+        if let Some(Some(multiplier)) = self.max_margin_multiplier {
+            if ![1, 2, 4].map(Decimal::from).contains(&multiplier) {
+                return Err("`max_margin_multiplier` must be 1, 2 or 4".into());
+            }
+        }
+        if let Some(Some(level)) = self.max_options_trading_level {
+            if level > MAX_OPTIONS_TRADING_LEVEL {
+                return Err(format!(
+                    "`max_options_trading_level` must be at most {}",
+                    MAX_OPTIONS_TRADING_LEVEL,
+                ));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Endpoint for UpdateAccountConfigurations {
+    fn method(&self) -> Method {
+        Method::PATCH
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "account/configurations".into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        Ok(Some(("application/json", serde_json::to_vec(self)?)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn account_activities_of_a_type() {
+        let endpoint = AccountActivities::builder()
+            .activity_type(ActivityType::Div)
+            .date(NaiveDate::from_ymd_opt(2024, 1, 19).unwrap())
+            .direction(SortOrder::Ascending)
+            .page_size(50)
+            .build()
+            .unwrap();
+
+        assert_eq!(endpoint.endpoint(), "account/activities/DIV");
+        assert_eq!(
+            endpoint.parameters().to_query_string(),
+            "date=2024-01-19&direction=asc&page_size=50",
+        );
+        assert_eq!(
+            endpoint.page_tokens(),
+            PageTokens::LastItemId {
+                page_size: 50,
+            },
+        );
+    }
+
+    #[test]
+    fn account_activities_defaults() {
+        let endpoint = AccountActivities::builder().build().unwrap();
+
+        assert_eq!(endpoint.endpoint(), "account/activities");
+        assert_eq!(endpoint.parameters().to_query_string(), "");
+        assert_eq!(
+            endpoint.page_tokens(),
+            PageTokens::LastItemId {
+                page_size: 100,
+            },
+        );
+    }
+
+    #[test]
+    fn account_activities_date_with_range() {
+        let err = AccountActivities::builder()
+            .date(NaiveDate::from_ymd_opt(2024, 1, 19).unwrap())
+            .after("2024-01-18T00:00:00Z".parse().unwrap())
+            .build()
+            .unwrap_err();
+
+        assert_eq!(err.to_string(), "`date` cannot be combined with `until` or `after`");
+    }
+
+    #[test]
+    fn account_activities_page_size() {
+        for page_size in [0, 101] {
+            let err = AccountActivities::builder().page_size(page_size).build().unwrap_err();
+            assert_eq!(err.to_string(), "`page_size` must be between 1 and 100");
+        }
+    }
+
+    #[test]
+    fn portfolio_history_params() {
+        let endpoint = PortfolioHistory::builder()
+            .period(Period::new(1, PeriodUnit::Month))
+            .timeframe(HistoryTimeFrame::OneDay)
+            .end("2024-01-31T21:00:00Z".parse().unwrap())
+            .pnl_reset(PnlReset::NoReset)
+            .build()
+            .unwrap();
+
+        assert_eq!(endpoint.endpoint(), "account/portfolio/history");
+        assert_eq!(
+            endpoint.parameters().to_query_string(),
+            "period=1M&timeframe=1D&end=2024-01-31T21%3A00%3A00Z&pnl_reset=no_reset",
+        );
+    }
+
+    #[test]
+    fn portfolio_history_empty_period() {
+        let err = PortfolioHistory::builder()
+            .period(Period::new(0, PeriodUnit::Day))
+            .build()
+            .unwrap_err();
+
+        assert_eq!(err.to_string(), "`period` must be at least one unit long");
+    }
+
+    #[test]
+    fn portfolio_history_period_with_range() {
+        let err = PortfolioHistory::builder()
+            .period(Period::new(1, PeriodUnit::Week))
+            .start("2024-01-01T00:00:00Z".parse().unwrap())
+            .end("2024-01-31T00:00:00Z".parse().unwrap())
+            .build()
+            .unwrap_err();
+
+        assert_eq!(err.to_string(), "`period` cannot be combined with both `start` and `end`");
+    }
+
+    #[test]
+    fn portfolio_history_start_after_end() {
+        let err = PortfolioHistory::builder()
+            .start("2024-01-31T00:00:00Z".parse().unwrap())
+            .end("2024-01-01T00:00:00Z".parse().unwrap())
+            .build()
+            .unwrap_err();
+
+        assert_eq!(err.to_string(), "`start` must not be after `end`");
+    }
+
+    #[test]
+    fn update_account_configurations_body() {
+        let endpoint = UpdateAccountConfigurations::builder()
+            .no_shorting(true)
+            .max_margin_multiplier(Decimal::from(2))
+            .pdt_check(DayTradeCheck::Exit)
+            .build()
+            .unwrap();
+
+        assert_eq!(endpoint.method(), Method::PATCH);
+        assert_eq!(endpoint.endpoint(), "account/configurations");
+        let (content_type, body) = endpoint.body().unwrap().unwrap();
+        assert_eq!(content_type, "application/json");
+        assert_eq!(
+            serde_json::from_slice::<serde_json::Value>(&body).unwrap(),
+            serde_json::json!({
+                "no_shorting": true,
+                "max_margin_multiplier": "2",
+                "pdt_check": "exit",
+            }),
+        );
+    }
+
+    #[test]
+    fn update_account_configurations_requires_a_change() {
+        let err = UpdateAccountConfigurations::builder().build().unwrap_err();
+
+        assert_eq!(err.to_string(), "at least one configuration must be updated");
+    }
+
+    #[test]
+    fn update_account_configurations_limits() {
+        let err = UpdateAccountConfigurations::builder()
+            .max_margin_multiplier(Decimal::from(3))
+            .build()
+            .unwrap_err();
+        assert_eq!(err.to_string(), "`max_margin_multiplier` must be 1, 2 or 4");
+
+        let err = UpdateAccountConfigurations::builder()
+            .max_options_trading_level(4)
+            .build()
+            .unwrap_err();
+        assert_eq!(err.to_string(), "`max_options_trading_level` must be at most 3");
+
+        assert!(UpdateAccountConfigurations::builder()
+            .max_options_trading_level(0)
+            .trade_confirm_email(TradeConfirmEmail::None)
+            .build()
+            .is_ok());
+    }
+}
@@ -1,10 +1,15 @@
 use crate::auth::{Auth, AuthError};
+use crate::environment::Environment;
+use crate::rate_limit::RateLimiter;
+use crate::retry::RetryPolicy;
 use reqwest::blocking::Client;
 use std::fmt;
 use std::fmt::Debug;
+use std::thread;
+use async_trait::async_trait;
 use bytes::Bytes;
 use thiserror::Error;
-use url::{ParseError, Url};
+use url::Url;
 use log::debug;
 use crate::error::ApiError;
 use http::{Response as HttpResponse};
@@ -20,29 +25,34 @@ pub enum AlpacaError {
     },
 }
 
-impl From<reqwest::Error> for AlpacaError {
-    fn from(error: reqwest::Error) -> Self {
-        // Convert reqwest::Error into AlpacaError here
-        // You may need to inspect `error` to decide how to handle it
-        // For example:
-        AlpacaError::UrlParse {
-            source: ParseError::EmptyHost,
-        }
-    }
-}
 type AlpacaResult<T> = Result<T, AlpacaError>;
 
+/// Join an endpoint path onto a base URL.
+///
+/// The path is made explicitly relative, since paths such as `orders:by_client_order_id` would
+/// otherwise parse as URLs with their own scheme.
+fn join_endpoint(base: &Url, endpoint: &str) -> Result<Url, url::ParseError> {
+    base.join(&format!("./{}", endpoint))
+}
+
 #[derive(Clone)]
 pub struct Alpaca {
     /// The client to use for API calls.
     client: Client,
     /// The base URL to use for API calls.
     rest_url: Url,
+    /// The base URL to use for market data API calls.
+    data_url: Url,
     /// The authentication information to use when communicating with Alpaca.
     auth: Auth,
+    /// How failed requests are retried, if at all.
+    retry_policy: Option<RetryPolicy>,
+    /// The limiter requests wait on before being sent, if any.
+    rate_limiter: Option<RateLimiter>,
 }
 
 impl Alpaca {
+    /// Create a client for a trading host, using the default market data host.
     pub fn new<Host, ApiKey, ApiSecret>(
         host: Host,
         api_key: ApiKey,
@@ -53,19 +63,65 @@ impl Alpaca {
         ApiKey: Into<String>,
         ApiSecret: Into<String>,
     {
-        let rest_url = Url::parse(&format!("https://{}/v2/", host.as_ref()))?;
-        let auth = Auth::SecretTokens(api_key.into(), secret_key.into());
+        Self::for_environment(Environment::trading_host(host)?, api_key, secret_key)
+    }
+
+    /// Create a client which sends no credentials.
+    ///
+    /// Such a client can only query endpoints which do not require auth, like crypto market data.
+    pub fn unauthenticated(environment: Environment) -> AlpacaResult<Self> {
+        Self::with_auth(environment, Auth::None)
+    }
+
+    /// Create a client for an Alpaca environment.
+    pub fn for_environment<ApiKey, ApiSecret>(
+        environment: Environment,
+        api_key: ApiKey,
+        secret_key: ApiSecret,
+    ) -> AlpacaResult<Self>
+    where
+        ApiKey: Into<String>,
+        ApiSecret: Into<String>,
+    {
+        Self::with_auth(environment, Auth::from_keys(api_key.into(), secret_key.into()))
+    }
+
+    fn with_auth(environment: Environment, auth: Auth) -> AlpacaResult<Self> {
+        let rest_url = environment.trading_url()?;
+        let data_url = environment.data_url()?;
         let client = Client::new();
 
         let api = Alpaca {
             client,
             rest_url,
+            data_url,
             auth,
+            retry_policy: None,
+            rate_limiter: None,
         };
 
         Ok(api)
     }
 
+    /// Retry requests which fail with rate limit or server errors according to a policy.
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = Some(retry_policy);
+        self
+    }
+
+    /// Wait on a rate limiter before sending each request.
+    ///
+    /// The limiter may be shared with other clients using the same API key.
+    pub fn with_rate_limiter(mut self, rate_limiter: RateLimiter) -> Self {
+        self.rate_limiter = Some(rate_limiter);
+        self
+    }
+
+    /// The rate limiter attached to the client, if any.
+    pub fn rate_limiter(&self) -> Option<&RateLimiter> {
+        self.rate_limiter.as_ref()
+    }
+
     /// Perform a REST query with a given auth.
     fn rest_auth(
         &self,
@@ -76,8 +132,33 @@ impl Alpaca {
         let call = || -> Result<_, RestError> {
             auth.set_header(request.headers_mut().unwrap())?;
             let http_request = request.body(body)?;
-            let request = http_request.try_into()?;
-            let rsp = self.client.execute(request)?;
+            let retry_policy = self
+                .retry_policy
+                .as_ref()
+                .filter(|policy| policy.allows(http_request.method(), http_request.body()));
+            let mut request: reqwest::blocking::Request = http_request.try_into()?;
+            let mut attempt = 1;
+            let rsp = loop {
+                let next_request = retry_policy.and_then(|_| request.try_clone());
+                if let Some(rate_limiter) = &self.rate_limiter {
+                    rate_limiter.acquire();
+                }
+                let rsp = self.client.execute(request)?;
+                if let Some(rate_limiter) = &self.rate_limiter {
+                    rate_limiter.update(rsp.headers());
+                }
+                let delay = retry_policy
+                    .and_then(|policy| policy.retry_delay(attempt, rsp.status(), rsp.headers()));
+                match (delay, next_request) {
+                    (Some(delay), Some(next_request)) => {
+                        debug!(target: "alpaca", "retrying after {} in {:?}", rsp.status(), delay);
+                        thread::sleep(delay);
+                        request = next_request;
+                        attempt += 1;
+                    },
+                    _ => break rsp,
+                }
+            };
 
             let mut http_rsp = HttpResponse::builder()
                 .status(rsp.status())
@@ -97,6 +178,9 @@ impl Debug for Alpaca {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         f.debug_struct("Alpaca")
             .field("rest_url", &self.rest_url)
+            .field("data_url", &self.data_url)
+            .field("retry_policy", &self.retry_policy)
+            .field("rate_limiter", &self.rate_limiter)
             .finish()
     }
 }
@@ -128,7 +212,12 @@ impl crate::client::RestClient for Alpaca {
 
     fn rest_endpoint(&self, endpoint: &str) -> Result<Url, ApiError<Self::Error>> {
         debug!(target: "alpaca", "REST api call {}", endpoint);
-        Ok(self.rest_url.join(endpoint)?)
+        Ok(join_endpoint(&self.rest_url, endpoint)?)
+    }
+
+    fn data_endpoint(&self, endpoint: &str) -> Result<Url, ApiError<Self::Error>> {
+        debug!(target: "alpaca", "market data api call {}", endpoint);
+        Ok(join_endpoint(&self.data_url, endpoint)?)
     }
 }
 
@@ -141,3 +230,260 @@ impl crate::client::Client for Alpaca {
         self.rest_auth(request, body, &self.auth)
     }
 }
+
+/// An asynchronous representation of the Alpaca API for a single user.
+///
+/// Separate users should use separate instances of this.
+#[derive(Clone)]
+pub struct AsyncAlpaca {
+    /// The client to use for API calls.
+    client: reqwest::Client,
+    /// The base URL to use for API calls.
+    rest_url: Url,
+    /// The base URL to use for market data API calls.
+    data_url: Url,
+    /// The authentication information to use when communicating with Alpaca.
+    auth: Auth,
+    /// How failed requests are retried, if at all.
+    retry_policy: Option<RetryPolicy>,
+    /// The limiter requests wait on before being sent, if any.
+    rate_limiter: Option<RateLimiter>,
+}
+
+impl AsyncAlpaca {
+    /// Create a client for a trading host, using the default market data host.
+    pub fn new<Host, ApiKey, ApiSecret>(
+        host: Host,
+        api_key: ApiKey,
+        secret_key: ApiSecret,
+    ) -> AlpacaResult<Self>
+    where
+        Host: AsRef<str>,
+        ApiKey: Into<String>,
+        ApiSecret: Into<String>,
+    {
+        Self::for_environment(Environment::trading_host(host)?, api_key, secret_key)
+    }
+
+    /// Create a client which sends no credentials.
+    ///
+    /// Such a client can only query endpoints which do not require auth, like crypto market data.
+    pub fn unauthenticated(environment: Environment) -> AlpacaResult<Self> {
+        Self::with_auth(environment, Auth::None)
+    }
+
+    /// Create a client for an Alpaca environment.
+    pub fn for_environment<ApiKey, ApiSecret>(
+        environment: Environment,
+        api_key: ApiKey,
+        secret_key: ApiSecret,
+    ) -> AlpacaResult<Self>
+    where
+        ApiKey: Into<String>,
+        ApiSecret: Into<String>,
+    {
+        Self::with_auth(environment, Auth::from_keys(api_key.into(), secret_key.into()))
+    }
+
+    fn with_auth(environment: Environment, auth: Auth) -> AlpacaResult<Self> {
+        let rest_url = environment.trading_url()?;
+        let data_url = environment.data_url()?;
+        let client = reqwest::Client::new();
+
+        let api = AsyncAlpaca {
+            client,
+            rest_url,
+            data_url,
+            auth,
+            retry_policy: None,
+            rate_limiter: None,
+        };
+
+        Ok(api)
+    }
+
+    /// Retry requests which fail with rate limit or server errors according to a policy.
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = Some(retry_policy);
+        self
+    }
+
+    /// Wait on a rate limiter before sending each request.
+    ///
+    /// The limiter may be shared with other clients using the same API key.
+    pub fn with_rate_limiter(mut self, rate_limiter: RateLimiter) -> Self {
+        self.rate_limiter = Some(rate_limiter);
+        self
+    }
+
+    /// The rate limiter attached to the client, if any.
+    pub fn rate_limiter(&self) -> Option<&RateLimiter> {
+        self.rate_limiter.as_ref()
+    }
+
+    /// Perform an asynchronous REST query with a given auth.
+    async fn rest_async_auth(
+        &self,
+        mut request: Builder,
+        body: Vec<u8>,
+        auth: &Auth,
+    ) -> Result<HttpResponse<Bytes>, ApiError<<Self as crate::client::RestClient>::Error>> {
+        let call = async {
+            auth.set_header(request.headers_mut().unwrap())?;
+            let http_request = request.body(body)?;
+            let retry_policy = self
+                .retry_policy
+                .as_ref()
+                .filter(|policy| policy.allows(http_request.method(), http_request.body()));
+            let mut request: reqwest::Request = http_request.try_into()?;
+            let mut attempt = 1;
+            let rsp = loop {
+                let next_request = retry_policy.and_then(|_| request.try_clone());
+                if let Some(rate_limiter) = &self.rate_limiter {
+                    rate_limiter.acquire_async().await;
+                }
+                let rsp = self.client.execute(request).await?;
+                if let Some(rate_limiter) = &self.rate_limiter {
+                    rate_limiter.update(rsp.headers());
+                }
+                let delay = retry_policy
+                    .and_then(|policy| policy.retry_delay(attempt, rsp.status(), rsp.headers()));
+                match (delay, next_request) {
+                    (Some(delay), Some(next_request)) => {
+                        debug!(target: "alpaca", "retrying after {} in {:?}", rsp.status(), delay);
+                        tokio::time::sleep(delay).await;
+                        request = next_request;
+                        attempt += 1;
+                    },
+                    _ => break rsp,
+                }
+            };
+
+            let mut http_rsp = HttpResponse::builder()
+                .status(rsp.status())
+                .version(rsp.version());
+            let headers = http_rsp.headers_mut().unwrap();
+            for (key, value) in rsp.headers() {
+                headers.insert(key, value.clone());
+            }
+            Ok::<_, RestError>(http_rsp.body(rsp.bytes().await?)?)
+        };
+        call.await.map_err(ApiError::client)
+    }
+}
+
+impl Debug for AsyncAlpaca {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("AsyncAlpaca")
+            .field("rest_url", &self.rest_url)
+            .field("data_url", &self.data_url)
+            .field("retry_policy", &self.retry_policy)
+            .field("rate_limiter", &self.rate_limiter)
+            .finish()
+    }
+}
+
+impl crate::client::RestClient for AsyncAlpaca {
+    type Error = RestError;
+
+    fn rest_endpoint(&self, endpoint: &str) -> Result<Url, ApiError<Self::Error>> {
+        debug!(target: "alpaca", "REST api call {}", endpoint);
+        Ok(join_endpoint(&self.rest_url, endpoint)?)
+    }
+
+    fn data_endpoint(&self, endpoint: &str) -> Result<Url, ApiError<Self::Error>> {
+        debug!(target: "alpaca", "market data api call {}", endpoint);
+        Ok(join_endpoint(&self.data_url, endpoint)?)
+    }
+}
+
+#[async_trait]
+impl crate::client::AsyncClient for AsyncAlpaca {
+    async fn rest_async(
+        &self,
+        request: Builder,
+        body: Vec<u8>,
+    ) -> Result<HttpResponse<Bytes>, ApiError<Self::Error>> {
+        self.rest_async_auth(request, body, &self.auth).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::client::RestClient;
+    use super::*;
+
+    #[test]
+    fn join_endpoint_appends_path() {
+        let base = Url::parse("https://paper-api.alpaca.markets/v2/").unwrap();
+
+        let url = join_endpoint(&base, "orders").unwrap();
+        assert_eq!(url.as_str(), "https://paper-api.alpaca.markets/v2/orders");
+    }
+
+    #[test]
+    fn join_endpoint_keeps_colon_paths_relative() {
+        let base = Url::parse("https://paper-api.alpaca.markets/v2/").unwrap();
+
+        let url = join_endpoint(&base, "orders:by_client_order_id").unwrap();
+        assert_eq!(
+            url.as_str(),
+            "https://paper-api.alpaca.markets/v2/orders:by_client_order_id",
+        );
+        crate::query::url_to_http_uri(url);
+    }
+
+    #[test]
+    fn join_endpoint_keeps_escaped_segments() {
+        let base = Url::parse("https://paper-api.alpaca.markets/v2/").unwrap();
+
+        let url = join_endpoint(&base, "watchlists/1/BTC%2FUSD").unwrap();
+        assert_eq!(url.as_str(), "https://paper-api.alpaca.markets/v2/watchlists/1/BTC%2FUSD");
+        assert_eq!(
+            crate::query::url_to_http_uri(url).path(),
+            "/v2/watchlists/1/BTC%2FUSD",
+        );
+    }
+
+    #[test]
+    fn async_client_routes_endpoints() {
+        let client = AsyncAlpaca::for_environment(Environment::Paper, "key", "secret").unwrap();
+
+        assert_eq!(
+            client.rest_endpoint("orders").unwrap().as_str(),
+            "https://paper-api.alpaca.markets/v2/orders",
+        );
+        assert_eq!(
+            client.data_endpoint("v2/stocks/bars").unwrap().as_str(),
+            "https://data.alpaca.markets/v2/stocks/bars",
+        );
+    }
+
+    #[test]
+    fn async_client_for_a_host() {
+        let client = AsyncAlpaca::new("api.alpaca.markets", "key", "secret").unwrap();
+
+        assert_eq!(
+            client.rest_endpoint("account").unwrap().as_str(),
+            "https://api.alpaca.markets/v2/account",
+        );
+    }
+
+    #[test]
+    fn empty_keys_are_sent() {
+        let client = Alpaca::for_environment(Environment::Live, "", "").unwrap();
+        assert!(matches!(client.auth, Auth::SecretTokens(..)));
+
+        let client = AsyncAlpaca::for_environment(Environment::Live, "", "").unwrap();
+        assert!(matches!(client.auth, Auth::SecretTokens(..)));
+    }
+
+    #[test]
+    fn unauthenticated_clients_send_no_keys() {
+        let client = Alpaca::unauthenticated(Environment::Live).unwrap();
+        assert!(matches!(client.auth, Auth::None));
+
+        let client = AsyncAlpaca::unauthenticated(Environment::Live).unwrap();
+        assert!(matches!(client.auth, Auth::None));
+    }
+}
@@ -1,9 +1,13 @@
 use std::borrow::Cow;
+use derive_builder::Builder;
 use http::Method;
+use crate::common::CommaSeparatedList;
 use crate::endpoint::Endpoint;
+use crate::models::{AssetAttribute, AssetClass, AssetStatus, Exchange};
+use crate::params::QueryParams;
 use typed_builder::TypedBuilder;
 
-/// Query information about the API calling user.
+/// Query for a single asset.
 #[derive(Debug, Clone, TypedBuilder)]
 pub struct Asset {
     symbol_or_asset_id: String
@@ -19,3 +23,131 @@ impl Endpoint for Asset {
         format!("assets/{}", self.symbol_or_asset_id).into()
     }
 }
+
+/// Query for assets.
+///
+/// Returns a list of `models::Asset`.
+#[derive(Debug, Clone, Builder)]
+#[builder(setter(strip_option))]
+pub struct Assets {
+    /// Only return assets with this status.
+    #[builder(default)]
+    status: Option<AssetStatus>,
+    /// Only return assets of this class; Alpaca defaults to US equities.
+    #[builder(default)]
+    asset_class: Option<AssetClass>,
+    /// Only return assets listed on this exchange.
+    #[builder(default)]
+    exchange: Option<Exchange>,
+    /// Only return assets with any of these attributes.
+    #[builder(setter(name = "_attributes"), default, private)]
+    attributes: Option<CommaSeparatedList<AssetAttribute>>,
+}
+
+impl Assets {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> AssetsBuilder {
+        AssetsBuilder::default()
+    }
+}
+
+list_setters! {
+    impl AssetsBuilder {
+        /// Filter assets by an attribute.
+        attribute,
+        /// Filter assets by a set of attributes.
+        attributes => attributes: AssetAttribute, optional;
+    }
+}
+
+impl Endpoint for Assets {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "assets".into()
+    }
+
+    fn parameters(&self) -> QueryParams<'_> {
+        let mut params = QueryParams::default();
+
+        params
+            .push_opt("status", self.status.as_ref())
+            .push_opt("asset_class", self.asset_class.as_ref())
+            .push_opt("exchange", self.exchange.as_ref())
+            .push_opt("attributes", self.attributes.as_ref());
+
+        params
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+    use crate::models;
+    use super::*;
+
+    #[test]
+    fn assets_defaults() {
+        let endpoint = Assets::builder().build().unwrap();
+
+        assert_eq!(endpoint.endpoint(), "assets");
+        assert_eq!(endpoint.parameters().to_query_string(), "");
+    }
+
+    #[test]
+    fn assets_filters() {
+        let endpoint = Assets::builder()
+            .status(AssetStatus::Active)
+            .asset_class(AssetClass::UsEquity)
+            .exchange(Exchange::Nasdaq)
+            .attribute(AssetAttribute::HasOptions)
+            .attributes([AssetAttribute::Ipo, AssetAttribute::OptionsLateClose].into_iter())
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            endpoint.parameters().to_query_string(),
+            "status=active&asset_class=us_equity&exchange=NASDAQ\
+             &attributes=has_options%2Cipo%2Coptions_late_close",
+        );
+    }
+
+    #[test]
+    fn asset_endpoint() {
+        let endpoint = super::Asset::builder().symbol_or_asset_id("AAPL".into()).build();
+
+        assert_eq!(endpoint.endpoint(), "assets/AAPL");
+    }
+
+    #[test]
+    fn asset_attributes() {
+        let mut asset = json!({
+            "id": "b0b6dd9d-8b9b-48a9-ba46-b9d54906e415",
+            "class": "us_equity",
+            "exchange": "NASDAQ",
+            "symbol": "AAPL",
+            "name": "Apple Inc. Common Stock",
+            "status": "active",
+            "tradable": true,
+            "marginable": true,
+            "shortable": true,
+            "easy_to_borrow": true,
+            "fractionable": true,
+            "maintenance_margin_requirement": "30",
+            "attributes": ["has_options", "some_new_attribute"],
+        });
+
+        let decoded: models::Asset = serde_json::from_value(asset.clone()).unwrap();
+        assert_eq!(
+            decoded.attributes,
+            [AssetAttribute::HasOptions, AssetAttribute::Unknown("some_new_attribute".into())],
+        );
+        assert_eq!(decoded.min_order_size, None);
+
+        asset["attributes"] = serde_json::Value::Null;
+        let decoded: models::Asset = serde_json::from_value(asset).unwrap();
+        assert!(decoded.attributes.is_empty());
+    }
+}
@@ -17,9 +17,16 @@ type AuthResult<T> = Result<T, AuthError>;
 #[non_exhaustive]
 pub enum Auth {
     SecretTokens(String, String),
+    /// Send no credentials, e.g. for crypto market data.
+    None,
 }
 
 impl Auth {
+    /// Authenticate with a key pair.
+    pub fn from_keys(api_key: String, secret_key: String) -> Self {
+        Auth::SecretTokens(api_key, secret_key)
+    }
+
     pub fn set_header<'a>(
         &self,
         headers: &'a mut HeaderMap<HeaderValue>,
@@ -28,16 +35,60 @@ impl Auth {
             Auth::SecretTokens(api_key, secret_key) => {
                 let mut api_key_header_value = HeaderValue::from_str(api_key)?;
                 let mut secret_key_header_value = HeaderValue::from_str(secret_key)?;
-                let accept_header_value = HeaderValue::from_str("application/json")?;
                 api_key_header_value.set_sensitive(true);
                 secret_key_header_value.set_sensitive(true);
                 headers.insert("APCA-API-KEY-ID", api_key_header_value);
                 headers.insert("APCA-API-SECRET-KEY", secret_key_header_value);
-                headers.insert("accept", accept_header_value);
             }
-            _ => {}
+            Auth::None => {}
         }
+        let accept_header_value = HeaderValue::from_str("application/json")?;
+        headers.insert("accept", accept_header_value);
 
         Ok(headers)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty_keys_still_authenticate() {
+        let auth = Auth::from_keys(String::new(), String::new());
+        let mut headers = HeaderMap::new();
+        auth.set_header(&mut headers).unwrap();
+
+        assert!(matches!(auth, Auth::SecretTokens(..)));
+        assert_eq!(headers["APCA-API-KEY-ID"], "");
+        assert_eq!(headers["APCA-API-SECRET-KEY"], "");
+    }
+
+    #[test]
+    fn no_auth_sends_no_credentials() {
+        let mut headers = HeaderMap::new();
+        Auth::None.set_header(&mut headers).unwrap();
+
+        assert!(headers.get("APCA-API-KEY-ID").is_none());
+        assert!(headers.get("APCA-API-SECRET-KEY").is_none());
+        assert_eq!(headers["accept"], "application/json");
+    }
+
+    #[test]
+    fn keys_are_sent_as_sensitive_headers() {
+        let auth = Auth::from_keys("key".into(), "secret".into());
+        let mut headers = HeaderMap::new();
+        auth.set_header(&mut headers).unwrap();
+
+        assert_eq!(headers["APCA-API-KEY-ID"], "key");
+        assert_eq!(headers["APCA-API-SECRET-KEY"], "secret");
+        assert!(headers["APCA-API-SECRET-KEY"].is_sensitive());
+    }
+
+    #[test]
+    fn one_empty_key_still_authenticates() {
+        let auth = Auth::from_keys("key".into(), String::new());
+
+        assert!(matches!(auth, Auth::SecretTokens(..)));
+    }
+}
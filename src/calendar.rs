@@ -0,0 +1,144 @@
+use std::borrow::Cow;
+use chrono::NaiveDate;
+use derive_builder::Builder;
+use http::Method;
+use crate::endpoint::Endpoint;
+use crate::params::{ParamValue, QueryParams};
+
+/// Query for the market clock.
+///
+/// Returns a `models::Clock`.
+#[derive(Debug, Clone, Copy, Builder)]
+pub struct Clock {}
+
+impl Clock {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> ClockBuilder {
+        ClockBuilder::default()
+    }
+}
+
+impl Endpoint for Clock {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "clock".into()
+    }
+}
+
+/// The kind of date the `start` and `end` of a calendar query refer to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum CalendarDateType {
+    /// Trading dates.
+    Trading,
+    /// Settlement dates.
+    Settlement,
+}
+
+impl CalendarDateType {
+    /// The date type as a query parameter.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            CalendarDateType::Trading => "TRADING",
+            CalendarDateType::Settlement => "SETTLEMENT",
+        }
+    }
+}
+
+impl ParamValue<'static> for CalendarDateType {
+    fn as_value(&self) -> Cow<'static, str> {
+        self.as_str().into()
+    }
+}
+
+/// Query for the market calendar.
+///
+/// Returns a list of `models::CalendarDay`, or a `models::TradingCalendar` to answer questions
+/// about session times.
+#[derive(Debug, Clone, Copy, Builder)]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
+pub struct Calendar {
+    /// The first date to return.
+    #[builder(default)]
+    start: Option<NaiveDate>,
+    /// The last date to return.
+    #[builder(default)]
+    end: Option<NaiveDate>,
+    /// Whether `start` and `end` are trading or settlement dates.
+    #[builder(default)]
+    date_type: Option<CalendarDateType>,
+}
+
+impl Calendar {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> CalendarBuilder {
+        CalendarBuilder::default()
+    }
+}
+
+impl CalendarBuilder {
+    fn validate(&self) -> Result<(), String> {
+        if let (Some(Some(start)), Some(Some(end))) = (self.start, self.end) {
+            if start > end {
+                return Err("`start` must not be after `end`".into());
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Endpoint for Calendar {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "calendar".into()
+    }
+
+    fn parameters(&self) -> QueryParams<'_> {
+        let mut params = QueryParams::default();
+
+        params
+            .push_opt("start", self.start)
+            .push_opt("end", self.end)
+            .push_opt("date_type", self.date_type);
+
+        params
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn calendar_params() {
+        let endpoint = Calendar::builder()
+            .start(NaiveDate::from_ymd_opt(2024, 11, 1).unwrap())
+            .end(NaiveDate::from_ymd_opt(2024, 11, 30).unwrap())
+            .date_type(CalendarDateType::Settlement)
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            endpoint.parameters().to_query_string(),
+            "start=2024-11-01&end=2024-11-30&date_type=SETTLEMENT",
+        );
+    }
+
+    #[test]
+    fn calendar_start_after_end() {
+        let err = Calendar::builder()
+            .start(NaiveDate::from_ymd_opt(2024, 11, 30).unwrap())
+            .end(NaiveDate::from_ymd_opt(2024, 11, 1).unwrap())
+            .build()
+            .unwrap_err();
+
+        assert_eq!(err.to_string(), "`start` must not be after `end`");
+    }
+}
@@ -1,5 +1,7 @@
 use std::error::Error;
+use async_trait::async_trait;
 use url::Url;
+use crate::endpoint::UrlBase;
 use crate::error::ApiError;
 use http::request::Builder as RequestBuilder;
 use http::Response;
@@ -10,10 +12,24 @@ pub trait RestClient {
     /// The errors which may occur for this client.
     type Error: Error + Send + Sync + 'static;
 
-    /// Get the URL for a REST v4 endpoint for the client.
+    /// Get the URL for a REST v2 endpoint for the client.
     ///
     /// This method adds the hostname for the client's target instance.
     fn rest_endpoint(&self, endpoint: &str) -> Result<Url, ApiError<Self::Error>>;
+
+    /// Get the URL for a market data endpoint for the client.
+    ///
+    /// The endpoint includes the API version prefix (e.g. `v2/stocks/bars`). This method adds
+    /// the hostname for the client's market data instance. Clients which do not talk to the
+    /// market data API need not implement it.
+    fn data_endpoint(&self, endpoint: &str) -> Result<Url, ApiError<Self::Error>> {
+        let url_base = match endpoint.split('/').next() {
+            Some("v1beta1") => UrlBase::DataV1Beta1,
+            Some("v1beta3") => UrlBase::DataV1Beta3,
+            _ => UrlBase::DataV2,
+        };
+        Err(ApiError::unsupported_url_base(url_base))
+    }
 }
 
 /// A trait representing a client which can communicate with an Alpaca instance.
@@ -25,3 +41,14 @@ pub trait Client: RestClient {
         body: Vec<u8>,
     ) -> Result<Response<Bytes>, ApiError<Self::Error>>;
 }
+
+/// A trait representing an asynchronous client which can communicate with an Alpaca instance.
+#[async_trait]
+pub trait AsyncClient: RestClient {
+    /// Send a REST query asynchronously.
+    async fn rest_async(
+        &self,
+        request: RequestBuilder,
+        body: Vec<u8>,
+    ) -> Result<Response<Bytes>, ApiError<Self::Error>>;
+}
@@ -0,0 +1,212 @@
+use std::borrow::Cow;
+use std::fmt::Display;
+use std::iter;
+use itertools::Itertools;
+use percent_encoding::{utf8_percent_encode, AsciiSet, NON_ALPHANUMERIC};
+use crate::params::ParamValue;
+
+/// Declare setters adding one or many items to comma-separated list fields of a builder.
+///
+/// Lists an endpoint requires are held by its builder as `Option<CommaSeparatedList<T>>`; lists
+/// which are optional are held as `Option<Option<CommaSeparatedList<T>>>` and marked `optional`.
+macro_rules! list_setters {
+    (@list $list:expr) => {
+        $list.get_or_insert_with($crate::common::CommaSeparatedList::new)
+    };
+    (@list $list:expr, optional) => {
+        $list
+            .get_or_insert(None)
+            .get_or_insert_with($crate::common::CommaSeparatedList::new)
+    };
+    (
+        impl $builder:ident {
+            $(
+                $(#[$one_meta:meta])*
+                $one:ident,
+                $(#[$many_meta:meta])*
+                $many:ident => $field:ident: $item:ty $(, $optional:ident)?;
+            )+
+        }
+    ) => {
+        impl $builder {
+            $(
+                $(#[$one_meta])*
+                pub fn $one<S>(&mut self, item: S) -> &mut Self
+                    where
+                        S: Into<$item>,
+                {
+                    list_setters!(@list self.$field $(, $optional)?).push(item.into());
+                    self
+                }
+
+                $(#[$many_meta])*
+                pub fn $many<I, S>(&mut self, iter: I) -> &mut Self
+                    where
+                        I: Iterator<Item = S>,
+                        S: Into<$item>,
+                {
+                    list_setters!(@list self.$field $(, $optional)?).extend(iter.map(Into::into));
+                    self
+                }
+            )+
+        }
+    };
+}
+
+/// Declare `symbol` and `symbols` setters for the `symbols` list of a builder.
+///
+/// For a required list, a `validate_symbols` check that at least one symbol was given is declared
+/// as well.
+macro_rules! symbol_setters {
+    ($builder:ident) => {
+        list_setters! {
+            impl $builder {
+                /// Add a symbol to query.
+                symbol,
+                /// Add a set of symbols to query.
+                symbols => symbols: String;
+            }
+        }
+
+        impl $builder {
+            fn validate_symbols(&self) -> Result<(), String> {
+                if self.symbols.as_ref().is_none_or($crate::common::CommaSeparatedList::is_empty) {
+                    return Err("at least one symbol is required".into());
+                }
+
+                Ok(())
+            }
+        }
+    };
+    ($builder:ident, optional) => {
+        list_setters! {
+            impl $builder {
+                /// Filter by a symbol.
+                symbol,
+                /// Filter by a set of symbols.
+                symbols => symbols: String, optional;
+            }
+        }
+    };
+}
+
+/// The characters escaped in a path segment; everything but the unreserved characters.
+const PATH_SEGMENT: &AsciiSet = &NON_ALPHANUMERIC
+    .remove(b'-')
+    .remove(b'.')
+    .remove(b'_')
+    .remove(b'~');
+
+/// Escape a value for use as a single segment of an endpoint path.
+pub(crate) fn path_segment(segment: &str) -> impl Display + '_ {
+    utf8_percent_encode(segment, PATH_SEGMENT)
+}
+
+/// Whether an optional builder field has been given a value.
+pub(crate) fn is_set<T>(value: &Option<Option<T>>) -> bool {
+    matches!(value, Some(Some(_)))
+}
+
+/// Orderings for sorted results.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[non_exhaustive]
+pub enum SortOrder {
+    /// Values should be sorted with "higher" values after "lower" values.
+    Ascending,
+    /// Values should be sorted with "lower" values after "higher" values.
+    #[default]
+    Descending,
+}
+
+impl SortOrder {
+    /// The string representation of the sort order.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            SortOrder::Ascending => "asc",
+            SortOrder::Descending => "desc",
+        }
+    }
+}
+
+impl ParamValue<'static> for SortOrder {
+    fn as_value(&self) -> Cow<'static, str> {
+        self.as_str().into()
+    }
+}
+
+/// A comma-separated list of values.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CommaSeparatedList<T> {
+    data: Vec<T>,
+}
+
+impl<T> CommaSeparatedList<T> {
+    /// Create a new, empty comma-separated list.
+    pub fn new() -> Self {
+        Self {
+            data: Vec::new(),
+        }
+    }
+
+    /// Add an element to the list.
+    pub fn push(&mut self, item: T) {
+        self.data.push(item);
+    }
+
+    /// Whether the list is empty.
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+}
+
+impl<T> Default for CommaSeparatedList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> From<Vec<T>> for CommaSeparatedList<T> {
+    fn from(data: Vec<T>) -> Self {
+        Self {
+            data,
+        }
+    }
+}
+
+impl<T> iter::FromIterator<T> for CommaSeparatedList<T> {
+    fn from_iter<I>(iter: I) -> Self
+        where
+            I: IntoIterator<Item = T>,
+    {
+        Self {
+            data: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl<T> Extend<T> for CommaSeparatedList<T> {
+    fn extend<I>(&mut self, iter: I)
+        where
+            I: IntoIterator<Item = T>,
+    {
+        self.data.extend(iter)
+    }
+}
+
+impl<'a, T> ParamValue<'a> for CommaSeparatedList<T>
+    where
+        T: ParamValue<'a>,
+{
+    fn as_value(&self) -> Cow<'a, str> {
+        self.data.iter().map(ParamValue::as_value).join(",").into()
+    }
+}
+
+impl<'a, T> ParamValue<'a> for &CommaSeparatedList<T>
+    where
+        T: ParamValue<'a>,
+{
+    fn as_value(&self) -> Cow<'a, str> {
+        (*self).as_value()
+    }
+}
@@ -0,0 +1,430 @@
+use std::borrow::Cow;
+use chrono::NaiveDate;
+use derive_builder::Builder;
+use http::Method;
+use crate::common::{CommaSeparatedList, SortOrder};
+use crate::endpoint::{Endpoint, UrlBase};
+use crate::models::AnnouncementType;
+use crate::paged::Pageable;
+use crate::params::{ParamValue, QueryParams};
+
+/// The longest range of dates announcements may be queried for.
+const MAX_ANNOUNCEMENT_DAYS: i64 = 90;
+/// The maximum number of corporate actions Alpaca returns per page.
+const MAX_LIMIT: u64 = 1000;
+
+/// The date of an announcement that `since` and `until` refer to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum AnnouncementDateType {
+    /// The declaration date.
+    Declaration,
+    /// The ex-date.
+    Ex,
+    /// The record date.
+    Record,
+    /// The payable date.
+    Payable,
+}
+
+impl AnnouncementDateType {
+    /// The date type as a query parameter.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            AnnouncementDateType::Declaration => "declaration_date",
+            AnnouncementDateType::Ex => "ex_date",
+            AnnouncementDateType::Record => "record_date",
+            AnnouncementDateType::Payable => "payable_date",
+        }
+    }
+}
+
+impl ParamValue<'static> for AnnouncementDateType {
+    fn as_value(&self) -> Cow<'static, str> {
+        self.as_str().into()
+    }
+}
+
+/// Query for corporate action announcements.
+///
+/// Returns a list of `models::Announcement`. The range from `since` to `until` may span at most
+/// 90 days.
+#[derive(Debug, Clone, Builder)]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
+pub struct Announcements {
+    /// The types of announcements to return.
+    #[builder(setter(name = "_ca_types"), private)]
+    ca_types: CommaSeparatedList<AnnouncementType>,
+    /// The first date to return announcements for.
+    since: NaiveDate,
+    /// The last date to return announcements for.
+    until: NaiveDate,
+    /// Only return announcements for this symbol.
+    #[builder(setter(into), default)]
+    symbol: Option<String>,
+    /// Only return announcements for this CUSIP.
+    #[builder(setter(into), default)]
+    cusip: Option<String>,
+    /// The date of the announcements that `since` and `until` refer to.
+    #[builder(default)]
+    date_type: Option<AnnouncementDateType>,
+}
+
+impl Announcements {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> AnnouncementsBuilder {
+        AnnouncementsBuilder::default()
+    }
+}
+
+list_setters! {
+    impl AnnouncementsBuilder {
+        /// Add a type of announcement to return.
+        ca_type,
+        /// Add a set of types of announcements to return.
+        ca_types => ca_types: AnnouncementType;
+    }
+}
+
+impl AnnouncementsBuilder {
+    fn validate(&self) -> Result<(), String> {
+        if self.ca_types.as_ref().is_none_or(CommaSeparatedList::is_empty) {
+            return Err("at least one announcement type is required".into());
+        }
+        if let (Some(since), Some(until)) = (self.since, self.until) {
+            if since > until {
+                return Err("`since` must not be after `until`".into());
+            }
+            if (until - since).num_days() > MAX_ANNOUNCEMENT_DAYS {
+                return Err(format!(
+                    "`since` and `until` may be at most {} days apart",
+                    MAX_ANNOUNCEMENT_DAYS,
+                ));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Endpoint for Announcements {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "corporate_actions/announcements".into()
+    }
+
+    fn parameters(&self) -> QueryParams<'_> {
+        let mut params = QueryParams::default();
+
+        params
+            .push("ca_types", &self.ca_types)
+            .push("since", self.since)
+            .push("until", self.until)
+            .push_opt("symbol", self.symbol.as_ref())
+            .push_opt("cusip", self.cusip.as_ref())
+            .push_opt("date_type", self.date_type);
+
+        params
+    }
+}
+
+/// Query for a single corporate action announcement.
+///
+/// Returns a `models::Announcement`.
+#[derive(Debug, Clone, Builder)]
+pub struct Announcement {
+    /// The ID of the announcement.
+    #[builder(setter(into))]
+    id: String,
+}
+
+impl Announcement {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> AnnouncementBuilder {
+        AnnouncementBuilder::default()
+    }
+}
+
+impl Endpoint for Announcement {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("corporate_actions/announcements/{}", self.id).into()
+    }
+}
+
+/// The type of a corporate action in market data.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum CorporateActionType {
+    /// Forward splits.
+    ForwardSplit,
+    /// Reverse splits.
+    ReverseSplit,
+    /// Unit splits.
+    UnitSplit,
+    /// Stock dividends.
+    StockDividend,
+    /// Cash dividends.
+    CashDividend,
+    /// Spinoffs.
+    SpinOff,
+    /// Cash mergers.
+    CashMerger,
+    /// Stock mergers.
+    StockMerger,
+    /// Mergers paid in stock and cash.
+    StockAndCashMerger,
+    /// Redemptions.
+    Redemption,
+    /// Name changes.
+    NameChange,
+    /// Removals of worthless securities.
+    WorthlessRemoval,
+    /// Rights distributions.
+    RightsDistribution,
+}
+
+impl CorporateActionType {
+    /// The type as a query parameter.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            CorporateActionType::ForwardSplit => "forward_split",
+            CorporateActionType::ReverseSplit => "reverse_split",
+            CorporateActionType::UnitSplit => "unit_split",
+            CorporateActionType::StockDividend => "stock_dividend",
+            CorporateActionType::CashDividend => "cash_dividend",
+            CorporateActionType::SpinOff => "spin_off",
+            CorporateActionType::CashMerger => "cash_merger",
+            CorporateActionType::StockMerger => "stock_merger",
+            CorporateActionType::StockAndCashMerger => "stock_and_cash_merger",
+            CorporateActionType::Redemption => "redemption",
+            CorporateActionType::NameChange => "name_change",
+            CorporateActionType::WorthlessRemoval => "worthless_removal",
+            CorporateActionType::RightsDistribution => "rights_distribution",
+        }
+    }
+}
+
+impl ParamValue<'static> for CorporateActionType {
+    fn as_value(&self) -> Cow<'static, str> {
+        self.as_str().into()
+    }
+}
+
+/// Query for historical corporate actions.
+///
+/// Returns `models::CorporateActions`; when paged, each item is a `models::CorporateAction`.
+#[derive(Debug, Clone, Builder)]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
+pub struct CorporateActions {
+    /// Only return actions for these symbols.
+    #[builder(setter(name = "_symbols"), default, private)]
+    symbols: Option<CommaSeparatedList<String>>,
+    /// Only return actions for these CUSIPs.
+    #[builder(setter(name = "_cusips"), default, private)]
+    cusips: Option<CommaSeparatedList<String>>,
+    /// Only return actions of these types.
+    #[builder(setter(name = "_types"), default, private)]
+    types: Option<CommaSeparatedList<CorporateActionType>>,
+    /// The first date to return actions for.
+    #[builder(default)]
+    start: Option<NaiveDate>,
+    /// The last date to return actions for.
+    #[builder(default)]
+    end: Option<NaiveDate>,
+    /// The maximum number of actions per page.
+    #[builder(default)]
+    limit: Option<u64>,
+    /// The page to start from.
+    #[builder(setter(into), default)]
+    page_token: Option<String>,
+    /// The order of the actions by date.
+    #[builder(default)]
+    sort: Option<SortOrder>,
+}
+
+impl CorporateActions {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> CorporateActionsBuilder {
+        CorporateActionsBuilder::default()
+    }
+}
+
+list_setters! {
+    impl CorporateActionsBuilder {
+        /// Filter actions by a symbol.
+        symbol,
+        /// Filter actions by a set of symbols.
+        symbols => symbols: String, optional;
+        /// Filter actions by a CUSIP.
+        cusip,
+        /// Filter actions by a set of CUSIPs.
+        cusips => cusips: String, optional;
+        /// Filter actions by a type.
+        action_type,
+        /// Filter actions by a set of types.
+        action_types => types: CorporateActionType, optional;
+    }
+}
+
+impl CorporateActionsBuilder {
+    fn validate(&self) -> Result<(), String> {
+        if let (Some(Some(start)), Some(Some(end))) = (self.start, self.end) {
+            if start > end {
+                return Err("`start` must not be after `end`".into());
+            }
+        }
+        if let Some(Some(limit)) = self.limit {
+            if !(1..=MAX_LIMIT).contains(&limit) {
+                return Err(format!("`limit` must be between 1 and {}", MAX_LIMIT));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Endpoint for CorporateActions {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "corporate-actions".into()
+    }
+
+    fn url_base(&self) -> UrlBase {
+        UrlBase::DataV1Beta1
+    }
+
+    fn parameters(&self) -> QueryParams<'_> {
+        let mut params = QueryParams::default();
+
+        params
+            .push_opt("symbols", self.symbols.as_ref())
+            .push_opt("cusips", self.cusips.as_ref())
+            .push_opt("types", self.types.as_ref())
+            .push_opt("start", self.start)
+            .push_opt("end", self.end)
+            .push_opt("limit", self.limit)
+            .push_opt("page_token", self.page_token.as_ref())
+            .push_opt("sort", self.sort);
+
+        params
+    }
+}
+
+impl Pageable for CorporateActions {
+    fn items_field(&self) -> &'static str {
+        "corporate_actions"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn announcements_params() {
+        let endpoint = Announcements::builder()
+            .ca_type(AnnouncementType::Dividend)
+            .ca_types([AnnouncementType::Split, AnnouncementType::Merger].into_iter())
+            .since(date(2024, 1, 1))
+            .until(date(2024, 3, 31))
+            .symbol("AAPL")
+            .date_type(AnnouncementDateType::Ex)
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            endpoint.parameters().to_query_string(),
+            "ca_types=dividend%2Csplit%2Cmerger&since=2024-01-01&until=2024-03-31&symbol=AAPL\
+             &date_type=ex_date",
+        );
+    }
+
+    #[test]
+    fn announcements_require_a_type() {
+        let err = Announcements::builder()
+            .since(date(2024, 1, 1))
+            .until(date(2024, 1, 31))
+            .build()
+            .unwrap_err();
+        assert_eq!(err.to_string(), "at least one announcement type is required");
+
+        let err = Announcements::builder()
+            .ca_types(std::iter::empty::<AnnouncementType>())
+            .since(date(2024, 1, 1))
+            .until(date(2024, 1, 31))
+            .build()
+            .unwrap_err();
+        assert_eq!(err.to_string(), "at least one announcement type is required");
+    }
+
+    #[test]
+    fn announcements_range() {
+        let announcements = |since, until| {
+            Announcements::builder()
+                .ca_type(AnnouncementType::Split)
+                .since(since)
+                .until(until)
+                .build()
+        };
+
+        assert!(announcements(date(2024, 1, 1), date(2024, 3, 31)).is_ok());
+        assert_eq!(
+            announcements(date(2024, 1, 1), date(2024, 4, 1)).unwrap_err().to_string(),
+            "`since` and `until` may be at most 90 days apart",
+        );
+        assert_eq!(
+            announcements(date(2024, 2, 1), date(2024, 1, 1)).unwrap_err().to_string(),
+            "`since` must not be after `until`",
+        );
+    }
+
+    #[test]
+    fn corporate_actions_params() {
+        let endpoint = CorporateActions::builder()
+            .symbols(["AAPL", "NVDA"].into_iter())
+            .action_type(CorporateActionType::ForwardSplit)
+            .action_type(CorporateActionType::StockAndCashMerger)
+            .start(date(2024, 1, 1))
+            .limit(100)
+            .sort(SortOrder::Ascending)
+            .build()
+            .unwrap();
+
+        assert_eq!(endpoint.endpoint(), "corporate-actions");
+        assert_eq!(endpoint.url_base(), UrlBase::DataV1Beta1);
+        assert_eq!(
+            endpoint.parameters().to_query_string(),
+            "symbols=AAPL%2CNVDA&types=forward_split%2Cstock_and_cash_merger&start=2024-01-01\
+             &limit=100&sort=asc",
+        );
+    }
+
+    #[test]
+    fn corporate_actions_limits() {
+        for limit in [0, 1001] {
+            let err = CorporateActions::builder().limit(limit).build().unwrap_err();
+            assert_eq!(err.to_string(), "`limit` must be between 1 and 1000");
+        }
+
+        let err = CorporateActions::builder()
+            .start(date(2024, 2, 1))
+            .end(date(2024, 1, 1))
+            .build()
+            .unwrap_err();
+        assert_eq!(err.to_string(), "`start` must not be after `end`");
+    }
+}
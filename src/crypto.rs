@@ -0,0 +1,570 @@
+use std::borrow::Cow;
+use chrono::{DateTime, Utc};
+use derive_builder::Builder;
+use http::Method;
+use crate::common::{CommaSeparatedList, SortOrder};
+use crate::endpoint::{Endpoint, UrlBase};
+use crate::market_data::{validate_bars, validate_limit, TimeFrame};
+use crate::paged::Pageable;
+use crate::params::{ParamValue, QueryParams};
+
+/// The location of a crypto market.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[non_exhaustive]
+pub enum CryptoLocation {
+    /// Alpaca US.
+    #[default]
+    Us,
+    /// Kraken US.
+    Us1,
+    /// Kraken EU.
+    Eu1,
+}
+
+impl CryptoLocation {
+    /// The location as used in endpoint paths.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            CryptoLocation::Us => "us",
+            CryptoLocation::Us1 => "us-1",
+            CryptoLocation::Eu1 => "eu-1",
+        }
+    }
+}
+
+impl ParamValue<'static> for CryptoLocation {
+    fn as_value(&self) -> Cow<'static, str> {
+        self.as_str().into()
+    }
+}
+
+/// Query for historical bars of crypto symbols such as `BTC/USD`.
+///
+/// Returns `models::Bars`; when paged, each item is a `(symbol, models::Bar)` pair.
+#[derive(Debug, Clone, Builder)]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
+pub struct CryptoBars {
+    /// The location of the market.
+    #[builder(default)]
+    location: CryptoLocation,
+    /// The symbols to query.
+    #[builder(setter(name = "_symbols"), private)]
+    symbols: CommaSeparatedList<String>,
+    /// The time frame of each bar.
+    timeframe: TimeFrame,
+    /// The inclusive start of the interval.
+    #[builder(default)]
+    start: Option<DateTime<Utc>>,
+    /// The inclusive end of the interval.
+    #[builder(default)]
+    end: Option<DateTime<Utc>>,
+    /// The maximum number of bars per page across all symbols.
+    #[builder(default)]
+    limit: Option<u64>,
+    /// The page to start from.
+    #[builder(setter(into), default)]
+    page_token: Option<String>,
+    /// The order of the bars by timestamp.
+    #[builder(default)]
+    sort: Option<SortOrder>,
+}
+
+impl CryptoBars {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> CryptoBarsBuilder {
+        CryptoBarsBuilder::default()
+    }
+}
+
+symbol_setters!(CryptoBarsBuilder);
+
+impl CryptoBarsBuilder {
+    fn validate(&self) -> Result<(), String> {
+        self.validate_symbols()?;
+
+        validate_bars(self.timeframe, self.limit.flatten())
+    }
+}
+
+impl Endpoint for CryptoBars {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("crypto/{}/bars", self.location.as_str()).into()
+    }
+
+    fn url_base(&self) -> UrlBase {
+        UrlBase::DataV1Beta3
+    }
+
+    fn parameters(&self) -> QueryParams<'_> {
+        let mut params = QueryParams::default();
+
+        params
+            .push("symbols", &self.symbols)
+            .push("timeframe", self.timeframe)
+            .push_opt("start", self.start)
+            .push_opt("end", self.end)
+            .push_opt("limit", self.limit)
+            .push_opt("page_token", self.page_token.as_ref())
+            .push_opt("sort", self.sort);
+
+        params
+    }
+}
+
+impl Pageable for CryptoBars {
+    fn items_field(&self) -> &'static str {
+        "bars"
+    }
+}
+
+/// Query for historical trades of crypto symbols such as `BTC/USD`.
+///
+/// Returns `models::Trades`; when paged, each item is a `(symbol, models::Trade)` pair.
+#[derive(Debug, Clone, Builder)]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
+pub struct CryptoTrades {
+    /// The location of the market.
+    #[builder(default)]
+    location: CryptoLocation,
+    /// The symbols to query.
+    #[builder(setter(name = "_symbols"), private)]
+    symbols: CommaSeparatedList<String>,
+    /// The inclusive start of the interval.
+    #[builder(default)]
+    start: Option<DateTime<Utc>>,
+    /// The inclusive end of the interval.
+    #[builder(default)]
+    end: Option<DateTime<Utc>>,
+    /// The maximum number of trades per page across all symbols.
+    #[builder(default)]
+    limit: Option<u64>,
+    /// The page to start from.
+    #[builder(setter(into), default)]
+    page_token: Option<String>,
+    /// The order of the trades by timestamp.
+    #[builder(default)]
+    sort: Option<SortOrder>,
+}
+
+impl CryptoTrades {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> CryptoTradesBuilder {
+        CryptoTradesBuilder::default()
+    }
+}
+
+symbol_setters!(CryptoTradesBuilder);
+
+impl CryptoTradesBuilder {
+    fn validate(&self) -> Result<(), String> {
+        self.validate_symbols()?;
+
+        validate_limit(self.limit.flatten())
+    }
+}
+
+impl Endpoint for CryptoTrades {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("crypto/{}/trades", self.location.as_str()).into()
+    }
+
+    fn url_base(&self) -> UrlBase {
+        UrlBase::DataV1Beta3
+    }
+
+    fn parameters(&self) -> QueryParams<'_> {
+        let mut params = QueryParams::default();
+
+        params
+            .push("symbols", &self.symbols)
+            .push_opt("start", self.start)
+            .push_opt("end", self.end)
+            .push_opt("limit", self.limit)
+            .push_opt("page_token", self.page_token.as_ref())
+            .push_opt("sort", self.sort);
+
+        params
+    }
+}
+
+impl Pageable for CryptoTrades {
+    fn items_field(&self) -> &'static str {
+        "trades"
+    }
+}
+
+/// Query for historical quotes of crypto symbols such as `BTC/USD`.
+///
+/// Returns `models::Quotes`; when paged, each item is a `(symbol, models::Quote)` pair.
+#[derive(Debug, Clone, Builder)]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
+pub struct CryptoQuotes {
+    /// The location of the market.
+    #[builder(default)]
+    location: CryptoLocation,
+    /// The symbols to query.
+    #[builder(setter(name = "_symbols"), private)]
+    symbols: CommaSeparatedList<String>,
+    /// The inclusive start of the interval.
+    #[builder(default)]
+    start: Option<DateTime<Utc>>,
+    /// The inclusive end of the interval.
+    #[builder(default)]
+    end: Option<DateTime<Utc>>,
+    /// The maximum number of quotes per page across all symbols.
+    #[builder(default)]
+    limit: Option<u64>,
+    /// The page to start from.
+    #[builder(setter(into), default)]
+    page_token: Option<String>,
+    /// The order of the quotes by timestamp.
+    #[builder(default)]
+    sort: Option<SortOrder>,
+}
+
+impl CryptoQuotes {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> CryptoQuotesBuilder {
+        CryptoQuotesBuilder::default()
+    }
+}
+
+symbol_setters!(CryptoQuotesBuilder);
+
+impl CryptoQuotesBuilder {
+    fn validate(&self) -> Result<(), String> {
+        self.validate_symbols()?;
+
+        validate_limit(self.limit.flatten())
+    }
+}
+
+impl Endpoint for CryptoQuotes {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("crypto/{}/quotes", self.location.as_str()).into()
+    }
+
+    fn url_base(&self) -> UrlBase {
+        UrlBase::DataV1Beta3
+    }
+
+    fn parameters(&self) -> QueryParams<'_> {
+        let mut params = QueryParams::default();
+
+        params
+            .push("symbols", &self.symbols)
+            .push_opt("start", self.start)
+            .push_opt("end", self.end)
+            .push_opt("limit", self.limit)
+            .push_opt("page_token", self.page_token.as_ref())
+            .push_opt("sort", self.sort);
+
+        params
+    }
+}
+
+impl Pageable for CryptoQuotes {
+    fn items_field(&self) -> &'static str {
+        "quotes"
+    }
+}
+
+/// Query for the latest orderbook of crypto symbols such as `BTC/USD`.
+///
+/// Returns `models::LatestOrderbooks`.
+#[derive(Debug, Clone, Builder)]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate_symbols"))]
+pub struct LatestCryptoOrderbooks {
+    /// The location of the market.
+    #[builder(default)]
+    location: CryptoLocation,
+    /// The symbols to query.
+    #[builder(setter(name = "_symbols"), private)]
+    symbols: CommaSeparatedList<String>,
+}
+
+impl LatestCryptoOrderbooks {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> LatestCryptoOrderbooksBuilder {
+        LatestCryptoOrderbooksBuilder::default()
+    }
+}
+
+symbol_setters!(LatestCryptoOrderbooksBuilder);
+
+impl Endpoint for LatestCryptoOrderbooks {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("crypto/{}/latest/orderbooks", self.location.as_str()).into()
+    }
+
+    fn url_base(&self) -> UrlBase {
+        UrlBase::DataV1Beta3
+    }
+
+    fn parameters(&self) -> QueryParams<'_> {
+        let mut params = QueryParams::default();
+
+        params
+            .push("symbols", &self.symbols);
+
+        params
+    }
+}
+
+/// Query for the latest trade of crypto symbols such as `BTC/USD`.
+///
+/// Returns `models::LatestTrades`.
+#[derive(Debug, Clone, Builder)]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate_symbols"))]
+pub struct LatestCryptoTrades {
+    /// The location of the market.
+    #[builder(default)]
+    location: CryptoLocation,
+    /// The symbols to query.
+    #[builder(setter(name = "_symbols"), private)]
+    symbols: CommaSeparatedList<String>,
+}
+
+impl LatestCryptoTrades {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> LatestCryptoTradesBuilder {
+        LatestCryptoTradesBuilder::default()
+    }
+}
+
+symbol_setters!(LatestCryptoTradesBuilder);
+
+impl Endpoint for LatestCryptoTrades {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("crypto/{}/latest/trades", self.location.as_str()).into()
+    }
+
+    fn url_base(&self) -> UrlBase {
+        UrlBase::DataV1Beta3
+    }
+
+    fn parameters(&self) -> QueryParams<'_> {
+        let mut params = QueryParams::default();
+
+        params
+            .push("symbols", &self.symbols);
+
+        params
+    }
+}
+
+/// Query for the latest quote of crypto symbols such as `BTC/USD`.
+///
+/// Returns `models::LatestQuotes`.
+#[derive(Debug, Clone, Builder)]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate_symbols"))]
+pub struct LatestCryptoQuotes {
+    /// The location of the market.
+    #[builder(default)]
+    location: CryptoLocation,
+    /// The symbols to query.
+    #[builder(setter(name = "_symbols"), private)]
+    symbols: CommaSeparatedList<String>,
+}
+
+impl LatestCryptoQuotes {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> LatestCryptoQuotesBuilder {
+        LatestCryptoQuotesBuilder::default()
+    }
+}
+
+symbol_setters!(LatestCryptoQuotesBuilder);
+
+impl Endpoint for LatestCryptoQuotes {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("crypto/{}/latest/quotes", self.location.as_str()).into()
+    }
+
+    fn url_base(&self) -> UrlBase {
+        UrlBase::DataV1Beta3
+    }
+
+    fn parameters(&self) -> QueryParams<'_> {
+        let mut params = QueryParams::default();
+
+        params
+            .push("symbols", &self.symbols);
+
+        params
+    }
+}
+
+/// Query for the latest minute bar of crypto symbols such as `BTC/USD`.
+///
+/// Returns `models::LatestBars`.
+#[derive(Debug, Clone, Builder)]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate_symbols"))]
+pub struct LatestCryptoBars {
+    /// The location of the market.
+    #[builder(default)]
+    location: CryptoLocation,
+    /// The symbols to query.
+    #[builder(setter(name = "_symbols"), private)]
+    symbols: CommaSeparatedList<String>,
+}
+
+impl LatestCryptoBars {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> LatestCryptoBarsBuilder {
+        LatestCryptoBarsBuilder::default()
+    }
+}
+
+symbol_setters!(LatestCryptoBarsBuilder);
+
+impl Endpoint for LatestCryptoBars {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("crypto/{}/latest/bars", self.location.as_str()).into()
+    }
+
+    fn url_base(&self) -> UrlBase {
+        UrlBase::DataV1Beta3
+    }
+
+    fn parameters(&self) -> QueryParams<'_> {
+        let mut params = QueryParams::default();
+
+        params
+            .push("symbols", &self.symbols);
+
+        params
+    }
+}
+
+/// Query for snapshots of crypto symbols such as `BTC/USD`.
+///
+/// Returns `models::Snapshots`.
+#[derive(Debug, Clone, Builder)]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate_symbols"))]
+pub struct CryptoSnapshots {
+    /// The location of the market.
+    #[builder(default)]
+    location: CryptoLocation,
+    /// The symbols to query.
+    #[builder(setter(name = "_symbols"), private)]
+    symbols: CommaSeparatedList<String>,
+}
+
+impl CryptoSnapshots {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> CryptoSnapshotsBuilder {
+        CryptoSnapshotsBuilder::default()
+    }
+}
+
+symbol_setters!(CryptoSnapshotsBuilder);
+
+impl Endpoint for CryptoSnapshots {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("crypto/{}/snapshots", self.location.as_str()).into()
+    }
+
+    fn url_base(&self) -> UrlBase {
+        UrlBase::DataV1Beta3
+    }
+
+    fn parameters(&self) -> QueryParams<'_> {
+        let mut params = QueryParams::default();
+
+        params
+            .push("symbols", &self.symbols);
+
+        params
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Alpaca, Environment};
+    use super::*;
+
+    #[test]
+    fn crypto_endpoints_use_the_location() {
+        let bars = CryptoBars::builder()
+            .symbol("BTC/USD")
+            .timeframe(TimeFrame::HOUR)
+            .build()
+            .unwrap();
+        let orderbooks = LatestCryptoOrderbooks::builder()
+            .location(CryptoLocation::Eu1)
+            .symbol("BTC/EUR")
+            .build()
+            .unwrap();
+
+        assert_eq!(bars.endpoint(), "crypto/us/bars");
+        assert_eq!(orderbooks.endpoint(), "crypto/eu-1/latest/orderbooks");
+    }
+
+    #[test]
+    fn crypto_symbols_are_encoded() {
+        let client = Alpaca::unauthenticated(Environment::Live).unwrap();
+        let trades = CryptoTrades::builder()
+            .location(CryptoLocation::Us1)
+            .symbols(["BTC/USD", "ETH/USD"].into_iter())
+            .limit(50)
+            .build()
+            .unwrap();
+
+        let url = crate::query::endpoint_url(&trades, &client).unwrap();
+        assert_eq!(
+            url.as_str(),
+            "https://data.alpaca.markets/v1beta3/crypto/us-1/trades?symbols=BTC%2FUSD%2CETH%2FUSD\
+             &limit=50",
+        );
+    }
+
+    #[test]
+    fn crypto_data_require_a_symbol() {
+        let mut builder = CryptoBars::builder();
+        builder.timeframe(TimeFrame::DAY);
+        assert!(builder.build().is_err());
+        assert!(CryptoQuotes::builder().build().is_err());
+        assert!(LatestCryptoTrades::builder().build().is_err());
+        assert!(CryptoSnapshots::builder().build().is_err());
+    }
+
+    #[test]
+    fn crypto_data_limit_page_size() {
+        let mut builder = CryptoQuotes::builder();
+        builder.symbol("BTC/USD").limit(10001);
+        assert!(builder.build().is_err());
+    }
+}
@@ -1,19 +1,26 @@
 use std::borrow::Cow;
-use http::{self, header, Method, Request};
+use async_trait::async_trait;
+use http::Method;
 use serde::de::DeserializeOwned;
 use url::Url;
-use crate::client::{Client, RestClient};
+use crate::client::{AsyncClient, Client, RestClient};
 use crate::error::{ApiError, BodyError};
 use crate::params::QueryParams;
 use crate::query;
-use crate::query::Query;
+use crate::query::{AsyncQuery, Query};
 
 /// URL bases for endpoints.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 #[non_exhaustive]
 pub enum UrlBase {
-    /// An endpoint uses the API v4 URL prefix.
-    ApiV2
+    /// An endpoint uses the trading API v2 URL prefix.
+    ApiV2,
+    /// An endpoint uses the market data API v2 URL prefix.
+    DataV2,
+    /// An endpoint uses the market data API v1beta1 URL prefix.
+    DataV1Beta1,
+    /// An endpoint uses the market data API v1beta3 URL prefix.
+    DataV1Beta3,
 }
 
 impl UrlBase {
@@ -23,7 +30,10 @@ impl UrlBase {
             C: RestClient,
     {
         match self {
-            UrlBase::ApiV2 => client.rest_endpoint(endpoint)
+            UrlBase::ApiV2 => client.rest_endpoint(endpoint),
+            UrlBase::DataV2 => client.data_endpoint(&format!("v2/{}", endpoint)),
+            UrlBase::DataV1Beta1 => client.data_endpoint(&format!("v1beta1/{}", endpoint)),
+            UrlBase::DataV1Beta3 => client.data_endpoint(&format!("v1beta3/{}", endpoint)),
         }
     }
 }
@@ -43,7 +53,7 @@ pub trait Endpoint {
     }
 
     /// Query parameters for the endpoint.
-    fn parameters(&self) -> QueryParams {
+    fn parameters(&self) -> QueryParams<'_> {
         QueryParams::default()
     }
 
@@ -71,7 +81,7 @@ impl<E> Endpoint for &E
         (*self).url_base()
     }
 
-    fn parameters(&self) -> QueryParams {
+    fn parameters(&self) -> QueryParams<'_> {
         (*self).parameters()
     }
 
@@ -87,34 +97,166 @@ impl<E, T, C> Query<T, C> for E
         C: Client,
 {
     fn query(&self, client: &C) -> Result<T, ApiError<C::Error>> {
-        let mut url = self.url_base().endpoint_for(client, &self.endpoint())?;
-        self.parameters().add_to_url(&mut url);
-
-        let req = Request::builder()
-            .method(self.method())
-            .uri(query::url_to_http_uri(url));
-        let (req, data) = if let Some((mime, data)) = self.body()? {
-            let req = req.header(header::CONTENT_TYPE, mime);
-            (req, data)
-        } else {
-            (req, Vec::new())
-        };
+        let url = query::endpoint_url(self, client)?;
+        let (req, data) = query::endpoint_request(self, url)?;
         let rsp = client.rest(req, data)?;
-        let status = rsp.status();
-        let v = if let Ok(v) = serde_json::from_slice(rsp.body()) {
-            v
-        } else {
-            return Err(ApiError::server_error(status, rsp.body()));
-        };
-        if !status.is_success() {
-            return Err(ApiError::server_error(status, rsp.body()));
-        } else if status == http::StatusCode::MOVED_PERMANENTLY {
-            return Err(ApiError::moved_permanently(
-                rsp.headers().get(header::LOCATION),
-            ));
-        }
+        let v = query::response_json(&rsp)?;
+        serde_json::from_value::<T>(v).map_err(ApiError::data_type::<T>)
+    }
+}
+
+#[async_trait]
+impl<E, T, C> AsyncQuery<T, C> for E
+    where
+        E: Endpoint + Sync,
+        T: DeserializeOwned + 'static,
+        C: AsyncClient + Sync,
+{
+    async fn query_async(&self, client: &C) -> Result<T, ApiError<C::Error>> {
+        let url = query::endpoint_url(self, client)?;
+        let (req, data) = query::endpoint_request(self, url)?;
+        let rsp = client.rest_async(req, data).await?;
+        let v = query::response_json(&rsp)?;
         serde_json::from_value::<T>(v).map_err(ApiError::data_type::<T>)
     }
 }
 
+#[cfg(test)]
+mod tests {
+    use serde::Deserialize;
+    use serde_json::json;
+    use crate::test_client::{MockClient, MockError};
+    use super::*;
+
+    /// A client which only knows the trading API.
+    struct TradingClient;
+
+    impl RestClient for TradingClient {
+        type Error = MockError;
+
+        fn rest_endpoint(&self, endpoint: &str) -> Result<Url, ApiError<Self::Error>> {
+            Ok(Url::parse("https://api.test/v2/")?.join(endpoint)?)
+        }
+    }
+
+    #[test]
+    fn trading_endpoints_use_rest_url() {
+        let url = UrlBase::ApiV2.endpoint_for(&TradingClient, "orders").unwrap();
+
+        assert_eq!(url.as_str(), "https://api.test/v2/orders");
+    }
+
+    #[test]
+    fn data_endpoints_are_unsupported_by_default() {
+        for url_base in [UrlBase::DataV2, UrlBase::DataV1Beta1, UrlBase::DataV1Beta3] {
+            match url_base.endpoint_for(&TradingClient, "news") {
+                Err(ApiError::UnsupportedUrlBase {
+                    url_base: unsupported,
+                }) => assert_eq!(unsupported, url_base),
+                other => panic!("expected an unsupported URL base, got {:?}", other),
+            }
+        }
+    }
+
+    /// An endpoint with parameters and a body.
+    struct Echo;
+
+    impl Endpoint for Echo {
+        fn method(&self) -> Method {
+            Method::POST
+        }
+
+        fn endpoint(&self) -> Cow<'static, str> {
+            "echo".into()
+        }
+
+        fn parameters(&self) -> QueryParams<'_> {
+            let mut params = QueryParams::default();
+            params.push("symbol", "AAPL");
+            params
+        }
+
+        fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+            Ok(Some(("application/json", b"{}".to_vec())))
+        }
+    }
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct Answer {
+        symbol: String,
+    }
+
+    fn error_body() -> Vec<u8> {
+        serde_json::to_vec(&json!({
+            "code": 40310000,
+            "message": "insufficient buying power",
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn query_decodes_responses() {
+        let client = MockClient::new(200, r#"{"symbol": "AAPL"}"#);
+
+        let answer: Answer = Echo.query(&client).unwrap();
+        assert_eq!(answer.symbol, "AAPL");
+
+        let requests = client.requests();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].method, Method::POST);
+        assert_eq!(requests[0].url, "https://api.test/v2/echo?symbol=AAPL");
+        assert_eq!(requests[0].content_type.as_deref(), Some("application/json"));
+        assert_eq!(requests[0].body, b"{}");
+    }
+
+    #[test]
+    fn query_reports_error_bodies() {
+        let client = MockClient::new(403, error_body());
+
+        let err = Query::<Answer, _>::query(&Echo, &client).unwrap_err();
+        assert_eq!(err.status(), Some(http::StatusCode::FORBIDDEN));
+        assert_eq!(err.alpaca_code(), Some(40310000));
+        assert!(err.is_insufficient_buying_power());
+    }
 
+    #[test]
+    fn query_reports_unexpected_data() {
+        let client = MockClient::new(200, r#"{"sym": "AAPL"}"#);
+
+        let err = Query::<Answer, _>::query(&Echo, &client).unwrap_err();
+        assert!(matches!(err, ApiError::DataType { .. }), "{:?}", err);
+    }
+
+    #[tokio::test]
+    async fn query_async_decodes_responses() {
+        let client = MockClient::new(200, r#"{"symbol": "AAPL"}"#);
+
+        let answer: Answer = Echo.query_async(&client).await.unwrap();
+        assert_eq!(answer.symbol, "AAPL");
+
+        let requests = client.requests();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].method, Method::POST);
+        assert_eq!(requests[0].url, "https://api.test/v2/echo?symbol=AAPL");
+        assert_eq!(requests[0].content_type.as_deref(), Some("application/json"));
+        assert_eq!(requests[0].body, b"{}");
+    }
+
+    #[tokio::test]
+    async fn query_async_reports_error_bodies() {
+        let client = MockClient::new(403, error_body());
+
+        let err = AsyncQuery::<Answer, _>::query_async(&Echo, &client).await.unwrap_err();
+        assert_eq!(err.status(), Some(http::StatusCode::FORBIDDEN));
+        assert_eq!(err.alpaca_code(), Some(40310000));
+        assert!(err.is_insufficient_buying_power());
+    }
+
+    #[tokio::test]
+    async fn query_async_reports_unexpected_data() {
+        let client = MockClient::new(200, r#"{"sym": "AAPL"}"#);
+
+        let err = AsyncQuery::<Answer, _>::query_async(&Echo, &client).await.unwrap_err();
+        assert!(matches!(err, ApiError::DataType { .. }), "{:?}", err);
+    }
+}
@@ -0,0 +1,114 @@
+use url::{ParseError, Url};
+
+/// The host of the paper trading API.
+const PAPER_HOST: &str = "paper-api.alpaca.markets";
+/// The host of the live trading API.
+const LIVE_HOST: &str = "api.alpaca.markets";
+/// The host of the market data API, shared by paper and live accounts.
+const DATA_HOST: &str = "data.alpaca.markets";
+
+/// The Alpaca environment a client communicates with.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Environment {
+    /// Paper trading.
+    Paper,
+    /// Live trading.
+    Live,
+    /// Custom base URLs, e.g. for a proxy or a mock server.
+    Custom {
+        /// The base URL of the trading API, without the version prefix.
+        trading: Url,
+        /// The base URL of the market data API, without the version prefix.
+        data: Url,
+    },
+}
+
+impl Environment {
+    /// Use a custom trading host with the default market data host.
+    pub fn trading_host<H>(host: H) -> Result<Self, ParseError>
+        where
+            H: AsRef<str>,
+    {
+        Ok(Environment::Custom {
+            trading: Url::parse(&format!("https://{}/", host.as_ref()))?,
+            data: Url::parse(&format!("https://{}/", DATA_HOST))?,
+        })
+    }
+
+    /// The base URL for REST v2 trading endpoints.
+    pub(crate) fn trading_url(&self) -> Result<Url, ParseError> {
+        match self {
+            Environment::Paper => Url::parse(&format!("https://{}/v2/", PAPER_HOST)),
+            Environment::Live => Url::parse(&format!("https://{}/v2/", LIVE_HOST)),
+            Environment::Custom {
+                trading, ..
+            } => as_base(trading).join("v2/"),
+        }
+    }
+
+    /// The base URL for market data endpoints, to which the version prefix is added.
+    pub(crate) fn data_url(&self) -> Result<Url, ParseError> {
+        match self {
+            Environment::Paper | Environment::Live => {
+                Url::parse(&format!("https://{}/", DATA_HOST))
+            },
+            Environment::Custom {
+                data, ..
+            } => Ok(as_base(data)),
+        }
+    }
+}
+
+/// Ensure a URL ends with a slash so that joining keeps its last path segment.
+fn as_base(url: &Url) -> Url {
+    let mut url = url.clone();
+    if !url.path().ends_with('/') {
+        let path = format!("{}/", url.path());
+        url.set_path(&path);
+    }
+    url
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn paper_and_live_urls() {
+        assert_eq!(
+            Environment::Paper.trading_url().unwrap().as_str(),
+            "https://paper-api.alpaca.markets/v2/",
+        );
+        assert_eq!(
+            Environment::Live.trading_url().unwrap().as_str(),
+            "https://api.alpaca.markets/v2/",
+        );
+        assert_eq!(
+            Environment::Live.data_url().unwrap().as_str(),
+            "https://data.alpaca.markets/",
+        );
+    }
+
+    #[test]
+    fn trading_host_uses_default_data_host() {
+        let environment = Environment::trading_host("localhost:8080").unwrap();
+
+        assert_eq!(environment.trading_url().unwrap().as_str(), "https://localhost:8080/v2/");
+        assert_eq!(environment.data_url().unwrap().as_str(), "https://data.alpaca.markets/");
+    }
+
+    #[test]
+    fn custom_urls_keep_their_paths() {
+        let environment = Environment::Custom {
+            trading: Url::parse("http://localhost/alpaca").unwrap(),
+            data: Url::parse("http://localhost/data").unwrap(),
+        };
+
+        assert_eq!(
+            environment.trading_url().unwrap().as_str(),
+            "http://localhost/alpaca/v2/",
+        );
+        assert_eq!(environment.data_url().unwrap().as_str(), "http://localhost/data/");
+    }
+}
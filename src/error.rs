@@ -1,5 +1,8 @@
 use std::any;
 use std::error::Error;
+use std::fmt;
+use serde::Deserialize;
+use serde_json::{Map, Value};
 use thiserror::Error;
 use crate::auth::AuthError;
 use crate::endpoint::UrlBase;
@@ -24,6 +27,34 @@ pub enum BodyError {
     },
 }
 
+/// The Alpaca error code for forbidden requests, including insufficient buying power.
+const CODE_FORBIDDEN: u64 = 40310000;
+/// The Alpaca error code for missing resources.
+const CODE_NOT_FOUND: u64 = 40410000;
+/// The Alpaca error code for rate-limited requests.
+const CODE_RATE_LIMITED: u64 = 42910000;
+
+/// The JSON body of an error returned by Alpaca.
+#[derive(Debug, Clone, Deserialize)]
+pub struct AlpacaErrorBody {
+    /// The Alpaca error code, e.g. `40010001`.
+    pub code: Option<u64>,
+    /// The error message.
+    pub message: String,
+    /// Any other fields sent with the error (e.g. `buying_power` or `symbol`).
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl fmt::Display for AlpacaErrorBody {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if let Some(code) = self.code {
+            write!(f, "{} (code {})", self.message, code)
+        } else {
+            f.write_str(&self.message)
+        }
+    }
+}
 
 /// Errors which may occur when using API endpoints.
 #[derive(Debug, Error)]
@@ -81,6 +112,14 @@ pub enum ApiError<E>
         /// The new location for the resource.
         location: Option<String>,
     },
+    /// Alpaca returned a structured error.
+    #[error("alpaca error {}: {}", status, body)]
+    AlpacaResponse {
+        /// The status code for the return.
+        status: http::StatusCode,
+        /// The error information from Alpaca.
+        body: AlpacaErrorBody,
+    },
     /// Alpaca returned an error message.
     #[error("alpaca server error: {}", msg)]
     Alpaca {
@@ -185,6 +224,15 @@ impl<E> ApiError<E>
                     url_base,
                 }
             },
+            Self::AlpacaResponse {
+                status,
+                body,
+            } => {
+                ApiError::AlpacaResponse {
+                    status,
+                    body,
+                }
+            },
             Self::Alpaca {
                 msg
             } => {
@@ -195,6 +243,58 @@ impl<E> ApiError<E>
         }
     }
 
+    /// The HTTP status returned by Alpaca, if the error came from a response.
+    pub fn status(&self) -> Option<http::StatusCode> {
+        match self {
+            Self::AlpacaResponse {
+                status,
+                ..
+            }
+            | Self::AlpacaService {
+                status,
+                ..
+            } => Some(*status),
+            _ => None,
+        }
+    }
+
+    /// The Alpaca error code, if Alpaca returned a structured error.
+    pub fn alpaca_code(&self) -> Option<u64> {
+        match self {
+            Self::AlpacaResponse {
+                body,
+                ..
+            } => body.code,
+            _ => None,
+        }
+    }
+
+    /// Whether the request was rejected for a lack of buying power.
+    pub fn is_insufficient_buying_power(&self) -> bool {
+        match self {
+            Self::AlpacaResponse {
+                status,
+                body,
+            } => {
+                (*status == http::StatusCode::FORBIDDEN || body.code == Some(CODE_FORBIDDEN))
+                    && body.message.to_lowercase().contains("buying power")
+            },
+            _ => false,
+        }
+    }
+
+    /// Whether the request was rejected by Alpaca's rate limit.
+    pub fn is_rate_limited(&self) -> bool {
+        self.status() == Some(http::StatusCode::TOO_MANY_REQUESTS)
+            || self.alpaca_code() == Some(CODE_RATE_LIMITED)
+    }
+
+    /// Whether the requested resource does not exist.
+    pub fn is_not_found(&self) -> bool {
+        self.status() == Some(http::StatusCode::NOT_FOUND)
+            || self.alpaca_code() == Some(CODE_NOT_FOUND)
+    }
+
     pub(crate) fn moved_permanently(raw_location: Option<&http::HeaderValue>) -> Self {
         let location = raw_location.map(|v| String::from_utf8_lossy(v.as_bytes()).into());
         Self::MovedPermanently {
@@ -209,6 +309,22 @@ impl<E> ApiError<E>
         }
     }
 
+    /// Create an error from an unsuccessful response from Alpaca.
+    pub(crate) fn from_response(status: http::StatusCode, body: &bytes::Bytes) -> Self {
+        if let Ok(body) = serde_json::from_slice::<AlpacaErrorBody>(body) {
+            Self::AlpacaResponse {
+                status,
+                body,
+            }
+        } else if let Ok(v) = serde_json::from_slice::<Value>(body) {
+            Self::Alpaca {
+                msg: v.as_str().map_or_else(|| v.to_string(), Into::into),
+            }
+        } else {
+            Self::server_error(status, body)
+        }
+    }
+
     pub(crate) fn server_error(status: http::StatusCode, body: &bytes::Bytes) -> Self {
         Self::AlpacaService {
             status,
@@ -216,7 +332,6 @@ impl<E> ApiError<E>
         }
     }
 
-
     pub(crate) fn unsupported_url_base(url_base: UrlBase) -> Self {
         Self::UnsupportedUrlBase {
             url_base,
@@ -224,3 +339,69 @@ impl<E> ApiError<E>
     }
 }
 
+
+#[cfg(test)]
+mod tests {
+    use http::StatusCode;
+    use super::*;
+
+    type TestError = ApiError<std::io::Error>;
+
+    fn from_response(status: StatusCode, body: &'static str) -> TestError {
+        ApiError::from_response(status, &bytes::Bytes::from_static(body.as_bytes()))
+    }
+
+    #[test]
+    fn decodes_structured_errors() {
+        let err = from_response(
+            StatusCode::FORBIDDEN,
+            r#"{"code": 40310000, "message": "insufficient buying power", "buying_power": "0"}"#,
+        );
+
+        assert_eq!(err.status(), Some(StatusCode::FORBIDDEN));
+        assert_eq!(err.alpaca_code(), Some(40310000));
+        assert!(err.is_insufficient_buying_power());
+        match err {
+            ApiError::AlpacaResponse {
+                body,
+                ..
+            } => assert_eq!(body.extra["buying_power"], "0"),
+            err => panic!("expected a structured error, got {:?}", err),
+        }
+    }
+
+    #[test]
+    fn decodes_errors_without_code() {
+        let err = from_response(StatusCode::NOT_FOUND, r#"{"message": "not found"}"#);
+
+        assert_eq!(err.alpaca_code(), None);
+        assert!(err.is_not_found());
+        assert!(!err.is_rate_limited());
+    }
+
+    #[test]
+    fn keeps_unstructured_json_errors() {
+        let err = from_response(StatusCode::BAD_REQUEST, r#""invalid symbol""#);
+
+        assert!(matches!(err, ApiError::Alpaca { msg } if msg == "invalid symbol"));
+    }
+
+    #[test]
+    fn keeps_non_json_errors() {
+        let err = from_response(StatusCode::TOO_MANY_REQUESTS, "rate limit exceeded");
+
+        assert!(err.is_rate_limited());
+        assert!(matches!(err, ApiError::AlpacaService { .. }));
+    }
+
+    #[test]
+    fn recognizes_rate_limits_by_code() {
+        let err = from_response(
+            StatusCode::FORBIDDEN,
+            r#"{"code": 42910000, "message": "rate limit exceeded"}"#,
+        );
+
+        assert!(err.is_rate_limited());
+        assert!(!err.is_insufficient_buying_power());
+    }
+}
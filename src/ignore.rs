@@ -0,0 +1,104 @@
+use async_trait::async_trait;
+use crate::client::{AsyncClient, Client};
+use crate::endpoint::Endpoint;
+use crate::error::ApiError;
+use crate::query;
+use crate::query::{AsyncQuery, Query};
+
+/// A query modifier that ignores the data returned from an endpoint.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Ignore<E> {
+    endpoint: E,
+}
+
+/// Ignore the resulting data from an endpoint.
+///
+/// Useful for endpoints such as order cancellation which answer with an empty body.
+pub fn ignore<E>(endpoint: E) -> Ignore<E> {
+    Ignore {
+        endpoint,
+    }
+}
+
+impl<E, C> Query<(), C> for Ignore<E>
+    where
+        E: Endpoint,
+        C: Client,
+{
+    fn query(&self, client: &C) -> Result<(), ApiError<C::Error>> {
+        let url = query::endpoint_url(&self.endpoint, client)?;
+        let (req, data) = query::endpoint_request(&self.endpoint, url)?;
+        let rsp = client.rest(req, data)?;
+        query::check_response(&rsp)?;
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl<E, C> AsyncQuery<(), C> for Ignore<E>
+    where
+        E: Endpoint + Sync,
+        C: AsyncClient + Sync,
+{
+    async fn query_async(&self, client: &C) -> Result<(), ApiError<C::Error>> {
+        let url = query::endpoint_url(&self.endpoint, client)?;
+        let (req, data) = query::endpoint_request(&self.endpoint, url)?;
+        let rsp = client.rest_async(req, data).await?;
+        query::check_response(&rsp)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+    use http::Method;
+    use crate::test_client::MockClient;
+    use super::*;
+
+    struct CancelOrders;
+
+    impl Endpoint for CancelOrders {
+        fn method(&self) -> Method {
+            Method::DELETE
+        }
+
+        fn endpoint(&self) -> Cow<'static, str> {
+            "orders".into()
+        }
+    }
+
+    #[test]
+    fn ignore_accepts_empty_bodies() {
+        let client = MockClient::new(204, "");
+
+        ignore(CancelOrders).query(&client).unwrap();
+        assert_eq!(client.requests()[0].method, Method::DELETE);
+    }
+
+    #[test]
+    fn ignore_reports_error_bodies() {
+        let client = MockClient::new(500, "internal server error");
+
+        let err = ignore(CancelOrders).query(&client).unwrap_err();
+        assert_eq!(err.status(), Some(http::StatusCode::INTERNAL_SERVER_ERROR));
+    }
+
+    #[tokio::test]
+    async fn ignore_async_accepts_empty_bodies() {
+        let client = MockClient::new(204, "");
+
+        ignore(CancelOrders).query_async(&client).await.unwrap();
+        assert_eq!(client.requests()[0].method, Method::DELETE);
+    }
+
+    #[tokio::test]
+    async fn ignore_async_reports_error_bodies() {
+        let client = MockClient::new(500, "internal server error");
+
+        let err = ignore(CancelOrders).query_async(&client).await.unwrap_err();
+        assert_eq!(err.status(), Some(http::StatusCode::INTERNAL_SERVER_ERROR));
+    }
+}
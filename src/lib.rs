@@ -0,0 +1,49 @@
+mod alpaca;
+mod auth;
+mod client;
+#[macro_use]
+mod common;
+mod endpoint;
+mod environment;
+mod error;
+mod ignore;
+mod option_symbol;
+mod paged;
+mod params;
+mod query;
+mod rate_limit;
+mod raw;
+mod retry;
+#[cfg(test)]
+mod test_client;
+
+pub mod account;
+pub mod asset;
+pub mod calendar;
+pub mod corporate_actions;
+pub mod crypto;
+pub mod market_data;
+pub mod models;
+pub mod news;
+pub mod options;
+pub mod options_contract;
+pub mod orders;
+pub mod positions;
+pub mod stocks;
+pub mod watchlists;
+
+pub use crate::alpaca::{Alpaca, AlpacaError, AsyncAlpaca, RestError};
+pub use crate::auth::{Auth, AuthError};
+pub use crate::client::{AsyncClient, Client, RestClient};
+pub use crate::common::{CommaSeparatedList, SortOrder};
+pub use crate::endpoint::{Endpoint, UrlBase};
+pub use crate::environment::Environment;
+pub use crate::error::{AlpacaErrorBody, ApiError, BodyError};
+pub use crate::ignore::{ignore, Ignore};
+pub use crate::option_symbol::{OptionSymbol, OptionSymbolError};
+pub use crate::paged::{paged, LazilyPagedIter, PageTokens, Pageable, Paged, Pagination};
+pub use crate::params::{ParamValue, QueryParams};
+pub use crate::query::{AsyncQuery, Query};
+pub use crate::rate_limit::RateLimiter;
+pub use crate::raw::{raw, Raw};
+pub use crate::retry::{RetryPolicy, RetryPolicyBuilder, RetryPolicyBuilderError};
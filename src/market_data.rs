@@ -0,0 +1,184 @@
+use std::borrow::Cow;
+use std::fmt;
+use crate::params::ParamValue;
+
+/// The maximum number of data points Alpaca returns per page.
+const MAX_LIMIT: u64 = 10000;
+
+/// The unit of a bar time frame.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum TimeFrameUnit {
+    /// Minutes (1-59).
+    Minute,
+    /// Hours (1-23).
+    Hour,
+    /// Days (1 only).
+    Day,
+    /// Weeks (1 only).
+    Week,
+    /// Months (1, 2, 3, 4, 6 or 12).
+    Month,
+}
+
+impl TimeFrameUnit {
+    /// The unit as used in a time frame parameter.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            TimeFrameUnit::Minute => "Min",
+            TimeFrameUnit::Hour => "Hour",
+            TimeFrameUnit::Day => "Day",
+            TimeFrameUnit::Week => "Week",
+            TimeFrameUnit::Month => "Month",
+        }
+    }
+}
+
+/// The time frame aggregated into each bar, e.g. `15Min` or `1Day`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TimeFrame {
+    amount: u32,
+    unit: TimeFrameUnit,
+}
+
+impl TimeFrame {
+    /// One-minute bars.
+    pub const MINUTE: TimeFrame = TimeFrame::new(1, TimeFrameUnit::Minute);
+    /// One-hour bars.
+    pub const HOUR: TimeFrame = TimeFrame::new(1, TimeFrameUnit::Hour);
+    /// Daily bars.
+    pub const DAY: TimeFrame = TimeFrame::new(1, TimeFrameUnit::Day);
+    /// Weekly bars.
+    pub const WEEK: TimeFrame = TimeFrame::new(1, TimeFrameUnit::Week);
+    /// Monthly bars.
+    pub const MONTH: TimeFrame = TimeFrame::new(1, TimeFrameUnit::Month);
+
+    /// Create a time frame of `amount` units.
+    ///
+    /// Endpoints reject time frames for which `is_valid` is false when built.
+    pub const fn new(amount: u32, unit: TimeFrameUnit) -> Self {
+        TimeFrame {
+            amount,
+            unit,
+        }
+    }
+
+    /// The number of units in the time frame.
+    pub fn amount(self) -> u32 {
+        self.amount
+    }
+
+    /// The unit of the time frame.
+    pub fn unit(self) -> TimeFrameUnit {
+        self.unit
+    }
+
+    /// Whether Alpaca supports the time frame.
+    pub fn is_valid(self) -> bool {
+        match self.unit {
+            TimeFrameUnit::Minute => (1..=59).contains(&self.amount),
+            TimeFrameUnit::Hour => (1..=23).contains(&self.amount),
+            TimeFrameUnit::Day | TimeFrameUnit::Week => self.amount == 1,
+            TimeFrameUnit::Month => matches!(self.amount, 1 | 2 | 3 | 4 | 6 | 12),
+        }
+    }
+}
+
+impl fmt::Display for TimeFrame {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}{}", self.amount, self.unit.as_str())
+    }
+}
+
+impl ParamValue<'static> for TimeFrame {
+    fn as_value(&self) -> Cow<'static, str> {
+        self.to_string().into()
+    }
+}
+
+/// Validate the parameters shared by historical bar endpoints.
+pub(crate) fn validate_bars(timeframe: Option<TimeFrame>, limit: Option<u64>) -> Result<(), String> {
+    if let Some(timeframe) = timeframe {
+        if !timeframe.is_valid() {
+            return Err(format!("unsupported time frame `{}`", timeframe));
+        }
+    }
+
+    validate_limit(limit)
+}
+
+/// Validate the page size of a historical data endpoint.
+pub(crate) fn validate_limit(limit: Option<u64>) -> Result<(), String> {
+    if let Some(limit) = limit {
+        if !(1..=MAX_LIMIT).contains(&limit) {
+            return Err(format!("`limit` must be between 1 and {}", MAX_LIMIT));
+        }
+    }
+
+    Ok(())
+}
+
+/// Validate the symbol of a single symbol endpoint.
+pub(crate) fn validate_symbol(symbol: Option<&str>) -> Result<(), String> {
+    if symbol.is_some_and(str::is_empty) {
+        return Err("`symbol` must not be empty".into());
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn time_frames_display_as_parameters() {
+        assert_eq!(TimeFrame::MINUTE.to_string(), "1Min");
+        assert_eq!(TimeFrame::new(15, TimeFrameUnit::Minute).to_string(), "15Min");
+        assert_eq!(TimeFrame::new(4, TimeFrameUnit::Hour).to_string(), "4Hour");
+        assert_eq!(TimeFrame::DAY.to_string(), "1Day");
+        assert_eq!(TimeFrame::WEEK.to_string(), "1Week");
+        assert_eq!(TimeFrame::new(3, TimeFrameUnit::Month).to_string(), "3Month");
+    }
+
+    #[test]
+    fn supported_time_frames() {
+        let cases = [
+            (1, TimeFrameUnit::Minute, true),
+            (59, TimeFrameUnit::Minute, true),
+            (60, TimeFrameUnit::Minute, false),
+            (0, TimeFrameUnit::Minute, false),
+            (23, TimeFrameUnit::Hour, true),
+            (24, TimeFrameUnit::Hour, false),
+            (1, TimeFrameUnit::Day, true),
+            (2, TimeFrameUnit::Day, false),
+            (1, TimeFrameUnit::Week, true),
+            (2, TimeFrameUnit::Week, false),
+            (6, TimeFrameUnit::Month, true),
+            (12, TimeFrameUnit::Month, true),
+            (5, TimeFrameUnit::Month, false),
+        ];
+
+        for (amount, unit, valid) in cases {
+            let timeframe = TimeFrame::new(amount, unit);
+            assert_eq!(timeframe.is_valid(), valid, "{}", timeframe);
+        }
+    }
+
+    #[test]
+    fn bar_validation() {
+        assert!(validate_bars(Some(TimeFrame::DAY), None).is_ok());
+        assert!(validate_bars(Some(TimeFrame::new(2, TimeFrameUnit::Day)), None).is_err());
+        assert!(validate_bars(None, Some(MAX_LIMIT)).is_ok());
+        assert!(validate_bars(None, Some(MAX_LIMIT + 1)).is_err());
+        assert!(validate_limit(Some(0)).is_err());
+        assert!(validate_limit(None).is_ok());
+    }
+
+    #[test]
+    fn symbol_validation() {
+        assert!(validate_symbol(Some("AAPL")).is_ok());
+        assert!(validate_symbol(None).is_ok());
+        assert!(validate_symbol(Some("")).is_err());
+    }
+}
@@ -0,0 +1,167 @@
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::Deserialize;
+
+string_enum! {
+    /// The status of an account.
+    pub enum AccountStatus {
+        /// The account is onboarding.
+        Onboarding => "ONBOARDING",
+        /// The account application submission failed.
+        SubmissionFailed => "SUBMISSION_FAILED",
+        /// The account application has been submitted for review.
+        Submitted => "SUBMITTED",
+        /// The account information is being updated.
+        AccountUpdated => "ACCOUNT_UPDATED",
+        /// The account is waiting for approval.
+        ApprovalPending => "APPROVAL_PENDING",
+        /// The account is active for trading.
+        Active => "ACTIVE",
+        /// The account application has been rejected.
+        Rejected => "REJECTED",
+    }
+}
+
+/// A trading account.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Account {
+    /// The ID of the account.
+    pub id: String,
+    /// The account number.
+    pub account_number: String,
+    /// The status of the account.
+    pub status: AccountStatus,
+    /// The status of the account for crypto trading.
+    pub crypto_status: Option<AccountStatus>,
+    /// The currency of the account.
+    pub currency: String,
+    /// The cash balance.
+    pub cash: Decimal,
+    /// The total value of cash and holdings.
+    pub portfolio_value: Option<Decimal>,
+    /// The buying power for non-marginable securities.
+    pub non_marginable_buying_power: Option<Decimal>,
+    /// Fees accrued this month.
+    pub accrued_fees: Option<Decimal>,
+    /// Cash pending transfer into the account.
+    pub pending_transfer_in: Option<Decimal>,
+    /// Cash pending transfer out of the account.
+    pub pending_transfer_out: Option<Decimal>,
+    /// Whether the account is flagged as a pattern day trader.
+    pub pattern_day_trader: bool,
+    /// Whether the user has suspended trading.
+    pub trade_suspended_by_user: bool,
+    /// Whether the account is blocked from trading.
+    pub trading_blocked: bool,
+    /// Whether the account is blocked from transferring funds.
+    pub transfers_blocked: bool,
+    /// Whether the account is blocked entirely.
+    pub account_blocked: bool,
+    /// When the account was created.
+    pub created_at: DateTime<Utc>,
+    /// Whether the account may open short positions.
+    pub shorting_enabled: bool,
+    /// The market value of long positions.
+    pub long_market_value: Decimal,
+    /// The market value of short positions.
+    pub short_market_value: Decimal,
+    /// The current equity.
+    pub equity: Decimal,
+    /// The equity as of the previous trading day.
+    pub last_equity: Decimal,
+    /// The buying power multiplier.
+    pub multiplier: Decimal,
+    /// The current buying power.
+    pub buying_power: Decimal,
+    /// The initial margin requirement.
+    pub initial_margin: Decimal,
+    /// The maintenance margin requirement.
+    pub maintenance_margin: Decimal,
+    /// The special memorandum account value.
+    pub sma: Option<Decimal>,
+    /// The number of day trades in the last five trading days.
+    pub daytrade_count: u64,
+    /// The maintenance margin requirement as of the previous trading day.
+    pub last_maintenance_margin: Decimal,
+    /// The buying power for day trades.
+    pub daytrading_buying_power: Decimal,
+    /// The Reg T buying power.
+    pub regt_buying_power: Decimal,
+    /// The buying power for options trading.
+    pub options_buying_power: Option<Decimal>,
+    /// The options trading level the account is approved for.
+    pub options_approved_level: Option<u8>,
+    /// The options trading level in effect for the account.
+    pub options_trading_level: Option<u8>,
+}
+
+string_enum! {
+    /// Which trades a day trading check applies to.
+    pub enum DayTradeCheck {
+        /// Check when entering a position.
+        Entry => "entry",
+        /// Check when exiting a position.
+        Exit => "exit",
+        /// Check when entering and exiting a position.
+        Both => "both",
+    }
+}
+
+string_enum! {
+    /// Which trade confirmations are emailed.
+    pub enum TradeConfirmEmail {
+        /// Email every trade confirmation.
+        All => "all",
+        /// Email no trade confirmations.
+        None => "none",
+    }
+}
+
+/// The configuration of an account.
+#[derive(Debug, Clone, Deserialize)]
+pub struct AccountConfigurations {
+    /// When the day trading buying power check is applied.
+    pub dtbp_check: DayTradeCheck,
+    /// Which trade confirmations are emailed.
+    pub trade_confirm_email: TradeConfirmEmail,
+    /// Whether new orders are blocked.
+    pub suspend_trade: bool,
+    /// Whether short selling is disabled.
+    pub no_shorting: bool,
+    /// Whether fractional trading is enabled.
+    pub fractional_trading: bool,
+    /// The maximum margin multiplier.
+    pub max_margin_multiplier: Decimal,
+    /// The maximum options trading level (0 to 3).
+    pub max_options_trading_level: Option<u8>,
+    /// When the pattern day trader check is applied.
+    pub pdt_check: DayTradeCheck,
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+    use super::*;
+
+    #[test]
+    fn account_configurations() {
+        let config: AccountConfigurations = serde_json::from_value(json!({
+            "dtbp_check": "entry",
+            "trade_confirm_email": "all",
+            "suspend_trade": false,
+            "no_shorting": false,
+            "fractional_trading": true,
+            "max_margin_multiplier": "4",
+            "max_options_trading_level": null,
+            "pdt_check": "both",
+            "ptp_no_exception_entry": false,
+        }))
+        .unwrap();
+
+        assert_eq!(config.dtbp_check, DayTradeCheck::Entry);
+        assert_eq!(config.trade_confirm_email, TradeConfirmEmail::All);
+        assert_eq!(config.max_margin_multiplier, Decimal::from(4));
+        assert_eq!(config.max_options_trading_level, None);
+        assert_eq!(config.pdt_check, DayTradeCheck::Both);
+    }
+}
@@ -0,0 +1,283 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Deserializer};
+use serde::de::Error;
+use serde_json::Value;
+use crate::models::{OrderSide, OrderStatus};
+
+string_enum! {
+    /// The type of an account activity.
+    pub enum ActivityType {
+        /// An order fill.
+        Fill => "FILL",
+        /// A cash transaction.
+        Trans => "TRANS",
+        /// A miscellaneous or rarely used activity.
+        Misc => "MISC",
+        /// An ACATS in/out of cash.
+        Acatc => "ACATC",
+        /// An ACATS in/out of securities.
+        Acats => "ACATS",
+        /// A crypto fee.
+        Cfee => "CFEE",
+        /// A cash deposit.
+        Csd => "CSD",
+        /// A cash withdrawal.
+        Csw => "CSW",
+        /// A dividend.
+        Div => "DIV",
+        /// A dividend of long-term capital gain.
+        Divcgl => "DIVCGL",
+        /// A dividend of short-term capital gain.
+        Divcgs => "DIVCGS",
+        /// A dividend fee.
+        Divfee => "DIVFEE",
+        /// A dividend adjusted for foreign tax withheld.
+        Divft => "DIVFT",
+        /// A dividend adjusted for NRA withheld.
+        Divnra => "DIVNRA",
+        /// A dividend return of capital.
+        Divroc => "DIVROC",
+        /// A dividend adjusted for tefra withheld.
+        Divtw => "DIVTW",
+        /// A tax-exempt dividend.
+        Divtxex => "DIVTXEX",
+        /// A fee denominated in USD.
+        Fee => "FEE",
+        /// Interest.
+        Int => "INT",
+        /// Interest adjusted for NRA withheld.
+        Intnra => "INTNRA",
+        /// Interest adjusted for tefra withheld.
+        Inttw => "INTTW",
+        /// A journal entry.
+        Jnl => "JNL",
+        /// A journal entry of cash.
+        Jnlc => "JNLC",
+        /// A journal entry of stock.
+        Jnls => "JNLS",
+        /// A merger or acquisition.
+        Ma => "MA",
+        /// A name change.
+        Nc => "NC",
+        /// An option assignment.
+        Opasn => "OPASN",
+        /// An option expiration.
+        Opexp => "OPEXP",
+        /// An option exercise.
+        Opxrc => "OPXRC",
+        /// A pass-through charge.
+        Ptc => "PTC",
+        /// A pass-through rebate.
+        Ptr => "PTR",
+        /// A reorganization.
+        Reorg => "REORG",
+        /// A symbol change.
+        Sc => "SC",
+        /// A stock spinoff.
+        Sso => "SSO",
+        /// A stock split.
+        Ssp => "SSP",
+        /// Cash in lieu of fractional shares.
+        Cil => "CIL",
+    }
+}
+
+/// An order fill activity.
+#[derive(Debug, Clone, Deserialize)]
+pub struct TradeActivity {
+    /// The ID of the activity.
+    pub id: String,
+    /// The type of the activity.
+    pub activity_type: ActivityType,
+    /// When the fill occurred.
+    pub transaction_time: DateTime<Utc>,
+    /// Whether the fill was partial (`partial_fill`) or complete (`fill`).
+    #[serde(rename = "type")]
+    pub fill_type: String,
+    /// The price of the fill.
+    pub price: Decimal,
+    /// The number of shares filled.
+    pub qty: Decimal,
+    /// The side of the fill.
+    pub side: OrderSide,
+    /// The symbol of the asset.
+    pub symbol: String,
+    /// The number of shares remaining on the order.
+    pub leaves_qty: Decimal,
+    /// The ID of the order.
+    pub order_id: String,
+    /// The cumulative number of shares filled on the order.
+    pub cum_qty: Decimal,
+    /// The status of the order after the fill.
+    pub order_status: Option<OrderStatus>,
+}
+
+/// A non-trade activity such as a dividend, fee or journal entry.
+#[derive(Debug, Clone, Deserialize)]
+pub struct NonTradeActivity {
+    /// The ID of the activity.
+    pub id: String,
+    /// The type of the activity.
+    pub activity_type: ActivityType,
+    /// The date of the activity.
+    pub date: NaiveDate,
+    /// The net amount of money involved.
+    pub net_amount: Decimal,
+    /// The symbol of the asset involved, if any.
+    pub symbol: Option<String>,
+    /// The number of shares involved, if any.
+    pub qty: Option<Decimal>,
+    /// The amount per share, if any.
+    pub per_share_amount: Option<Decimal>,
+    /// A description of the activity.
+    pub description: Option<String>,
+    /// The status of the activity.
+    pub status: Option<String>,
+}
+
+/// An account activity.
+///
+/// Activities are told apart by their `activity_type`: fills are trade activities, anything else
+/// is a non-trade activity.
+#[derive(Debug, Clone)]
+pub enum Activity {
+    /// An order fill.
+    Trade(TradeActivity),
+    /// Any other activity.
+    NonTrade(NonTradeActivity),
+}
+
+impl Activity {
+    /// The ID of the activity.
+    pub fn id(&self) -> &str {
+        match self {
+            Activity::Trade(activity) => &activity.id,
+            Activity::NonTrade(activity) => &activity.id,
+        }
+    }
+
+    /// The type of the activity.
+    pub fn activity_type(&self) -> &ActivityType {
+        match self {
+            Activity::Trade(activity) => &activity.activity_type,
+            Activity::NonTrade(activity) => &activity.activity_type,
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Activity {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+    {
+        let value = Value::deserialize(deserializer)?;
+        let activity_type = value
+            .get("activity_type")
+            .and_then(Value::as_str)
+            .map(ActivityType::from)
+            .ok_or_else(|| D::Error::missing_field("activity_type"))?;
+
+        match activity_type {
+            ActivityType::Fill => {
+                TradeActivity::deserialize(value)
+                    .map(Activity::Trade)
+                    .map_err(D::Error::custom)
+            },
+            _ => {
+                NonTradeActivity::deserialize(value)
+                    .map(Activity::NonTrade)
+                    .map_err(D::Error::custom)
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+    use super::*;
+
+    #[test]
+    fn fills_are_trade_activities() {
+        let activity: Activity = serde_json::from_value(json!({
+            "id": "20240119093000000::8efc7b9a-8b2b-4000-9955-d36e7db0df74",
+            "activity_type": "FILL",
+            "transaction_time": "2024-01-19T14:30:00Z",
+            "type": "partial_fill",
+            "price": "190.5",
+            "qty": "3",
+            "side": "buy",
+            "symbol": "AAPL",
+            "leaves_qty": "7",
+            "order_id": "904837e3-3b76-47ec-b432-046db621571b",
+            "cum_qty": "3",
+            "order_status": "partially_filled",
+        }))
+        .unwrap();
+
+        assert_eq!(activity.id(), "20240119093000000::8efc7b9a-8b2b-4000-9955-d36e7db0df74");
+        assert_eq!(*activity.activity_type(), ActivityType::Fill);
+        match activity {
+            Activity::Trade(trade) => {
+                assert_eq!(trade.fill_type, "partial_fill");
+                assert_eq!(trade.price, Decimal::new(1905, 1));
+                assert_eq!(trade.side, OrderSide::Buy);
+                assert_eq!(trade.order_status, Some(OrderStatus::PartiallyFilled));
+            },
+            activity => panic!("expected a trade activity, got {:?}", activity),
+        }
+    }
+
+    #[test]
+    fn other_types_are_non_trade_activities() {
+        let activities: Vec<Activity> = serde_json::from_value(json!([
+            {
+                "id": "20240119000000000::045b3b8d-c566-4bef-b741-2bf598dd6ae7",
+                "activity_type": "DIV",
+                "date": "2024-01-19",
+                "net_amount": "1.92",
+                "symbol": "AAPL",
+                "qty": "8",
+                "per_share_amount": "0.24",
+            },
+            {
+                "id": "20240120000000000::5e4b1bd6-0f6b-4e7e-8a3e-3e2f5e4a0a9f",
+                "activity_type": "SOMETHING_NEW",
+                "date": "2024-01-20",
+                "net_amount": "-1",
+                "description": "a new kind of activity",
+            },
+        ]))
+        .unwrap();
+
+        assert_eq!(*activities[0].activity_type(), ActivityType::Div);
+        match &activities[0] {
+            Activity::NonTrade(activity) => {
+                assert_eq!(activity.net_amount, Decimal::new(192, 2));
+                assert_eq!(activity.per_share_amount, Some(Decimal::new(24, 2)));
+            },
+            activity => panic!("expected a non-trade activity, got {:?}", activity),
+        }
+        assert_eq!(
+            *activities[1].activity_type(),
+            ActivityType::Unknown("SOMETHING_NEW".into()),
+        );
+        assert!(matches!(activities[1], Activity::NonTrade(_)));
+    }
+
+    #[test]
+    fn activity_type_is_required() {
+        let err = serde_json::from_value::<Activity>(json!({"id": "1"})).unwrap_err();
+        assert!(err.to_string().contains("activity_type"), "{}", err);
+
+        let err = serde_json::from_value::<Activity>(json!({
+            "id": "1",
+            "activity_type": "FILL",
+            "date": "2024-01-19",
+            "net_amount": "1",
+        }))
+        .unwrap_err();
+        assert!(err.to_string().contains("missing field"), "{}", err);
+    }
+}
@@ -0,0 +1,103 @@
+use rust_decimal::Decimal;
+use serde::Deserialize;
+use crate::models::null_as_default;
+
+string_enum! {
+    /// The class of an asset.
+    pub enum AssetClass {
+        /// US equities.
+        UsEquity => "us_equity",
+        /// US options.
+        UsOption => "us_option",
+        /// Cryptocurrencies.
+        Crypto => "crypto",
+    }
+}
+
+string_enum! {
+    /// The status of an asset.
+    pub enum AssetStatus {
+        /// The asset is active.
+        Active => "active",
+        /// The asset is inactive.
+        Inactive => "inactive",
+    }
+}
+
+string_enum! {
+    /// The exchange an asset is listed on.
+    pub enum Exchange {
+        /// NYSE American.
+        Amex => "AMEX",
+        /// NYSE Arca.
+        Arca => "ARCA",
+        /// Cboe BZX.
+        Bats => "BATS",
+        /// New York Stock Exchange.
+        Nyse => "NYSE",
+        /// Nasdaq.
+        Nasdaq => "NASDAQ",
+        /// NYSE Arca.
+        NyseArca => "NYSEARCA",
+        /// Over the counter.
+        Otc => "OTC",
+        /// Cryptocurrency venues.
+        Crypto => "CRYPTO",
+    }
+}
+
+string_enum! {
+    /// An attribute of an asset.
+    pub enum AssetAttribute {
+        /// A publicly traded partnership exempt from withholding.
+        PtpNoException => "ptp_no_exception",
+        /// A publicly traded partnership subject to withholding.
+        PtpWithException => "ptp_with_exception",
+        /// A recent or upcoming IPO.
+        Ipo => "ipo",
+        /// Options are listed on the asset.
+        HasOptions => "has_options",
+        /// Options on the asset trade until 4:15pm ET.
+        OptionsLateClose => "options_late_close",
+        /// Fractional orders are accepted during extended hours.
+        FractionalEhEnabled => "fractional_eh_enabled",
+    }
+}
+
+/// A tradable asset.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Asset {
+    /// The ID of the asset.
+    pub id: String,
+    /// The class of the asset.
+    pub class: AssetClass,
+    /// The exchange of the asset.
+    pub exchange: Exchange,
+    /// The symbol of the asset.
+    pub symbol: String,
+    /// The name of the asset.
+    pub name: String,
+    /// The status of the asset.
+    pub status: AssetStatus,
+    /// Whether the asset is tradable on Alpaca.
+    pub tradable: bool,
+    /// Whether the asset is marginable.
+    pub marginable: bool,
+    /// Whether the asset is shortable.
+    pub shortable: bool,
+    /// Whether the asset is easy to borrow.
+    pub easy_to_borrow: bool,
+    /// Whether the asset supports fractional trading.
+    pub fractionable: bool,
+    /// The maintenance margin requirement as a percentage.
+    pub maintenance_margin_requirement: Option<Decimal>,
+    /// The minimum order size.
+    pub min_order_size: Option<Decimal>,
+    /// The minimum quantity increment.
+    pub min_trade_increment: Option<Decimal>,
+    /// The minimum price increment.
+    pub price_increment: Option<Decimal>,
+    /// Additional attributes of the asset.
+    #[serde(default, deserialize_with = "null_as_default")]
+    pub attributes: Vec<AssetAttribute>,
+}
@@ -0,0 +1,316 @@
+use chrono::{DateTime, NaiveDate, NaiveTime, TimeZone, Utc};
+use chrono_tz::America::New_York;
+use serde::{Deserialize, Deserializer};
+use serde::de::Error;
+
+/// The market clock.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Clock {
+    /// The current time.
+    pub timestamp: DateTime<Utc>,
+    /// Whether the market is currently open.
+    pub is_open: bool,
+    /// When the market opens next.
+    pub next_open: DateTime<Utc>,
+    /// When the market closes next.
+    pub next_close: DateTime<Utc>,
+}
+
+/// A trading day on the market calendar.
+///
+/// Times are in the exchange's local time (America/New_York).
+#[derive(Debug, Clone, Deserialize)]
+pub struct CalendarDay {
+    /// The date of the trading day.
+    pub date: NaiveDate,
+    /// When the regular session opens.
+    #[serde(deserialize_with = "deserialize_time")]
+    pub open: NaiveTime,
+    /// When the regular session closes.
+    #[serde(deserialize_with = "deserialize_time")]
+    pub close: NaiveTime,
+    /// When the extended pre-market session opens.
+    #[serde(default, deserialize_with = "deserialize_opt_time")]
+    pub session_open: Option<NaiveTime>,
+    /// When the extended post-market session closes.
+    #[serde(default, deserialize_with = "deserialize_opt_time")]
+    pub session_close: Option<NaiveTime>,
+    /// The settlement date for trades made on this day.
+    pub settlement_date: Option<NaiveDate>,
+}
+
+impl CalendarDay {
+    /// When the regular session opens.
+    pub fn open_time(&self) -> DateTime<Utc> {
+        exchange_time(self.date, self.open)
+    }
+
+    /// When the regular session closes.
+    pub fn close_time(&self) -> DateTime<Utc> {
+        exchange_time(self.date, self.close)
+    }
+
+    /// Whether the regular session is open at a point in time.
+    pub fn is_open_at(&self, at: DateTime<Utc>) -> bool {
+        self.open_time() <= at && at < self.close_time()
+    }
+}
+
+/// The trading days of a range of dates, sorted by date.
+///
+/// Answers questions about the regular session within the range only; days outside of it are
+/// unknown rather than closed, so `is_open_at` returns `None` for them.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(from = "Vec<CalendarDay>")]
+pub struct TradingCalendar {
+    days: Vec<CalendarDay>,
+}
+
+impl TradingCalendar {
+    /// The trading days of the calendar.
+    pub fn days(&self) -> &[CalendarDay] {
+        &self.days
+    }
+
+    /// The trading day on a date, if the market is open that day.
+    pub fn day(&self, date: NaiveDate) -> Option<&CalendarDay> {
+        self.days
+            .binary_search_by_key(&date, |day| day.date)
+            .ok()
+            .map(|index| &self.days[index])
+    }
+
+    /// Whether the regular session is open at a point in time.
+    ///
+    /// Returns `None` if the point in time is on a date outside of the calendar.
+    pub fn is_open_at(&self, at: DateTime<Utc>) -> Option<bool> {
+        let date = exchange_date(at);
+        let (first, last) = (self.days.first()?, self.days.last()?);
+        if date < first.date || last.date < date {
+            return None;
+        }
+
+        Some(self.day(date).is_some_and(|day| day.is_open_at(at)))
+    }
+
+    /// When the regular session next opens after a point in time, if within the calendar.
+    pub fn next_open(&self, after: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        self.days_from(exchange_date(after))
+            .iter()
+            .map(CalendarDay::open_time)
+            .find(|&open| open > after)
+    }
+
+    /// When the regular session next closes after a point in time, if within the calendar.
+    pub fn next_close(&self, after: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        self.days_from(exchange_date(after))
+            .iter()
+            .map(CalendarDay::close_time)
+            .find(|&close| close > after)
+    }
+
+    /// The trading days from `start` to `end`, inclusive.
+    pub fn trading_days_between(&self, start: NaiveDate, end: NaiveDate) -> &[CalendarDay] {
+        let from = self.days.partition_point(|day| day.date < start);
+        let to = self.days.partition_point(|day| day.date <= end);
+        self.days.get(from..to).unwrap_or_default()
+    }
+
+    fn days_from(&self, date: NaiveDate) -> &[CalendarDay] {
+        &self.days[self.days.partition_point(|day| day.date < date)..]
+    }
+}
+
+impl From<Vec<CalendarDay>> for TradingCalendar {
+    fn from(mut days: Vec<CalendarDay>) -> Self {
+        days.sort_by_key(|day| day.date);
+        TradingCalendar {
+            days,
+        }
+    }
+}
+
+/// The date at the exchange at a point in time.
+pub(crate) fn exchange_date(at: DateTime<Utc>) -> NaiveDate {
+    at.with_timezone(&New_York).date_naive()
+}
+
+/// A local time at the exchange on a date.
+fn exchange_time(date: NaiveDate, time: NaiveTime) -> DateTime<Utc> {
+    let local = date.and_time(time);
+    // Sessions never start within a daylight saving transition, but stay total regardless.
+    New_York
+        .from_local_datetime(&local)
+        .earliest()
+        .map(|at| at.with_timezone(&Utc))
+        .unwrap_or_else(|| Utc.from_utc_datetime(&local))
+}
+
+/// Parse a calendar time, which Alpaca sends as either `HH:MM` or `HHMM`.
+fn parse_time<E>(value: &str) -> Result<NaiveTime, E>
+    where
+        E: Error,
+{
+    NaiveTime::parse_from_str(value, "%H:%M")
+        .or_else(|_| NaiveTime::parse_from_str(value, "%H%M"))
+        .map_err(|err| E::custom(format!("invalid calendar time `{}`: {}", value, err)))
+}
+
+fn deserialize_time<'de, D>(deserializer: D) -> Result<NaiveTime, D::Error>
+    where
+        D: Deserializer<'de>,
+{
+    let value = String::deserialize(deserializer)?;
+    parse_time(&value)
+}
+
+fn deserialize_opt_time<'de, D>(deserializer: D) -> Result<Option<NaiveTime>, D::Error>
+    where
+        D: Deserializer<'de>,
+{
+    Option::<String>::deserialize(deserializer)?
+        .map(|value| parse_time(&value))
+        .transpose()
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+    use super::*;
+
+    fn utc(value: &str) -> DateTime<Utc> {
+        value.parse().unwrap()
+    }
+
+    fn date(value: &str) -> NaiveDate {
+        value.parse().unwrap()
+    }
+
+    /// Thanksgiving week of 2024, with the early close on Friday and the following Monday.
+    fn calendar() -> TradingCalendar {
+        serde_json::from_value(json!([
+            {"date": "2024-12-02", "open": "09:30", "close": "16:00"},
+            {"date": "2024-11-29", "open": "09:30", "close": "13:00"},
+            {"date": "2024-11-27", "open": "0930", "close": "1600"},
+            {
+                "date": "2024-11-26",
+                "open": "09:30",
+                "close": "16:00",
+                "session_open": "0400",
+                "session_close": "2000",
+                "settlement_date": "2024-11-27",
+            },
+        ]))
+        .unwrap()
+    }
+
+    #[test]
+    fn days_are_sorted() {
+        let calendar = calendar();
+        let dates: Vec<_> = calendar.days().iter().map(|day| day.date).collect();
+
+        assert_eq!(
+            dates,
+            [date("2024-11-26"), date("2024-11-27"), date("2024-11-29"), date("2024-12-02")],
+        );
+        assert_eq!(calendar.days()[0].session_open, NaiveTime::from_hms_opt(4, 0, 0));
+        assert_eq!(calendar.days()[0].settlement_date, Some(date("2024-11-27")));
+        assert!(calendar.day(date("2024-11-28")).is_none());
+    }
+
+    #[test]
+    fn rejects_bad_times() {
+        let days = json!([{"date": "2024-11-26", "open": "9.30", "close": "16:00"}]);
+
+        assert!(serde_json::from_value::<TradingCalendar>(days).is_err());
+    }
+
+    #[test]
+    fn session_boundaries() {
+        let calendar = calendar();
+
+        // EST is UTC-5.
+        assert_eq!(calendar.is_open_at(utc("2024-11-26T14:29:59Z")), Some(false));
+        assert_eq!(calendar.is_open_at(utc("2024-11-26T14:30:00Z")), Some(true));
+        assert_eq!(calendar.is_open_at(utc("2024-11-26T20:59:59Z")), Some(true));
+        assert_eq!(calendar.is_open_at(utc("2024-11-26T21:00:00Z")), Some(false));
+    }
+
+    #[test]
+    fn session_boundaries_in_daylight_saving_time() {
+        let calendar: TradingCalendar = serde_json::from_value(json!([
+            {"date": "2024-07-01", "open": "09:30", "close": "16:00"},
+        ]))
+        .unwrap();
+        let day = &calendar.days()[0];
+
+        // EDT is UTC-4.
+        assert_eq!(day.open_time(), utc("2024-07-01T13:30:00Z"));
+        assert_eq!(day.close_time(), utc("2024-07-01T20:00:00Z"));
+        assert_eq!(calendar.is_open_at(utc("2024-07-01T13:29:59Z")), Some(false));
+        assert_eq!(calendar.is_open_at(utc("2024-07-01T13:30:00Z")), Some(true));
+        assert_eq!(calendar.is_open_at(utc("2024-07-01T20:00:00Z")), Some(false));
+    }
+
+    #[test]
+    fn early_closes() {
+        let calendar = calendar();
+
+        assert_eq!(calendar.is_open_at(utc("2024-11-29T17:59:59Z")), Some(true));
+        assert_eq!(calendar.is_open_at(utc("2024-11-29T18:00:00Z")), Some(false));
+        assert_eq!(
+            calendar.next_close(utc("2024-11-29T15:00:00Z")),
+            Some(utc("2024-11-29T18:00:00Z")),
+        );
+    }
+
+    #[test]
+    fn closed_days_within_the_calendar() {
+        let calendar = calendar();
+
+        // Thanksgiving and the weekend.
+        assert_eq!(calendar.is_open_at(utc("2024-11-28T16:00:00Z")), Some(false));
+        assert_eq!(calendar.is_open_at(utc("2024-11-30T16:00:00Z")), Some(false));
+    }
+
+    #[test]
+    fn days_outside_the_calendar() {
+        let calendar = calendar();
+
+        assert_eq!(calendar.is_open_at(utc("2024-11-25T16:00:00Z")), None);
+        assert_eq!(calendar.is_open_at(utc("2024-12-03T16:00:00Z")), None);
+        assert_eq!(TradingCalendar::from(Vec::new()).is_open_at(utc("2024-11-26T16:00:00Z")), None);
+        // Late on the last day in UTC is still that day at the exchange.
+        assert_eq!(calendar.is_open_at(utc("2024-12-03T01:00:00Z")), Some(false));
+    }
+
+    #[test]
+    fn next_sessions_across_weekends() {
+        let calendar = calendar();
+        let friday_after_close = utc("2024-11-29T18:00:00Z");
+
+        assert_eq!(calendar.next_open(friday_after_close), Some(utc("2024-12-02T14:30:00Z")));
+        assert_eq!(calendar.next_close(friday_after_close), Some(utc("2024-12-02T21:00:00Z")));
+        assert_eq!(
+            calendar.next_open(utc("2024-11-30T12:00:00Z")),
+            Some(utc("2024-12-02T14:30:00Z")),
+        );
+        assert_eq!(calendar.next_open(utc("2024-12-02T14:30:00Z")), None);
+        assert_eq!(calendar.next_close(utc("2024-12-02T21:00:00Z")), None);
+    }
+
+    #[test]
+    fn trading_days_between() {
+        let calendar = calendar();
+
+        let days = calendar.trading_days_between(date("2024-11-27"), date("2024-11-30"));
+        let dates: Vec<_> = days.iter().map(|day| day.date).collect();
+        assert_eq!(dates, [date("2024-11-27"), date("2024-11-29")]);
+        assert!(calendar
+            .trading_days_between(date("2024-12-03"), date("2024-12-31"))
+            .is_empty());
+        assert!(calendar
+            .trading_days_between(date("2024-11-29"), date("2024-11-27"))
+            .is_empty());
+    }
+}
@@ -0,0 +1,619 @@
+use std::collections::HashMap;
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Deserializer};
+use serde::de::Error;
+use serde_json::Value;
+use crate::models::null_as_default;
+
+string_enum! {
+    /// The type of a corporate action announcement.
+    pub enum AnnouncementType {
+        /// A dividend.
+        Dividend => "dividend",
+        /// A merger.
+        Merger => "merger",
+        /// A spinoff.
+        Spinoff => "spinoff",
+        /// A split.
+        Split => "split",
+    }
+}
+
+/// A corporate action announcement.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Announcement {
+    /// The ID of the announcement.
+    pub id: String,
+    /// The ID of the corporate action.
+    pub corporate_action_id: String,
+    /// The type of the corporate action.
+    pub ca_type: AnnouncementType,
+    /// The sub-type of the corporate action, e.g. `cash` or `reverse_split`.
+    pub ca_sub_type: String,
+    /// The symbol of the company initiating the action.
+    pub initiating_symbol: Option<String>,
+    /// The original CUSIP of the initiating company.
+    pub initiating_original_cusip: Option<String>,
+    /// The symbol of the company targeted by the action.
+    pub target_symbol: Option<String>,
+    /// The original CUSIP of the targeted company.
+    pub target_original_cusip: Option<String>,
+    /// When the action was declared.
+    pub declaration_date: Option<NaiveDate>,
+    /// The first date the security trades without the action.
+    pub ex_date: Option<NaiveDate>,
+    /// The date shareholders must be on record to be eligible.
+    pub record_date: Option<NaiveDate>,
+    /// When the action is paid out.
+    pub payable_date: Option<NaiveDate>,
+    /// The cash paid per share, if any.
+    pub cash: Option<Decimal>,
+    /// The number of old shares in the exchange ratio.
+    pub old_rate: Option<Decimal>,
+    /// The number of new shares in the exchange ratio.
+    pub new_rate: Option<Decimal>,
+}
+
+/// A forward or reverse stock split.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Split {
+    /// The ID of the action.
+    pub id: Option<String>,
+    /// The symbol of the security.
+    pub symbol: String,
+    /// The CUSIP of the security.
+    pub cusip: Option<String>,
+    /// The number of shares after the split for every `old_rate` shares.
+    pub new_rate: Decimal,
+    /// The number of shares before the split for every `new_rate` shares.
+    pub old_rate: Decimal,
+    /// When the split was processed.
+    pub process_date: Option<NaiveDate>,
+    /// The first date the security trades split-adjusted.
+    pub ex_date: Option<NaiveDate>,
+    /// The record date of the split.
+    pub record_date: Option<NaiveDate>,
+    /// When the new shares are distributed.
+    pub payable_date: Option<NaiveDate>,
+}
+
+impl Split {
+    /// The number of new shares per old share.
+    ///
+    /// Prices before the ex-date are divided by this ratio, and volumes multiplied by it, to adjust
+    /// them for the split. Returns `None` if `old_rate` is zero.
+    pub fn ratio(&self) -> Option<Decimal> {
+        self.new_rate.checked_div(self.old_rate)
+    }
+}
+
+/// A cash dividend.
+#[derive(Debug, Clone, Deserialize)]
+pub struct CashDividend {
+    /// The ID of the action.
+    pub id: Option<String>,
+    /// The symbol of the security.
+    pub symbol: String,
+    /// The CUSIP of the security.
+    pub cusip: Option<String>,
+    /// The cash paid per share.
+    pub rate: Decimal,
+    /// Whether the dividend is a special dividend.
+    #[serde(default)]
+    pub special: bool,
+    /// Whether the dividend is paid by a foreign company.
+    #[serde(default)]
+    pub foreign: bool,
+    /// When the dividend was processed.
+    pub process_date: Option<NaiveDate>,
+    /// The first date the security trades without the dividend.
+    pub ex_date: Option<NaiveDate>,
+    /// The record date of the dividend.
+    pub record_date: Option<NaiveDate>,
+    /// When the dividend is paid.
+    pub payable_date: Option<NaiveDate>,
+}
+
+/// A dividend paid in stock.
+#[derive(Debug, Clone, Deserialize)]
+pub struct StockDividend {
+    /// The ID of the action.
+    pub id: Option<String>,
+    /// The symbol of the security.
+    pub symbol: String,
+    /// The CUSIP of the security.
+    pub cusip: Option<String>,
+    /// The number of new shares paid per share.
+    pub rate: Decimal,
+    /// When the dividend was processed.
+    pub process_date: Option<NaiveDate>,
+    /// The first date the security trades without the dividend.
+    pub ex_date: Option<NaiveDate>,
+    /// The record date of the dividend.
+    pub record_date: Option<NaiveDate>,
+    /// When the dividend is paid.
+    pub payable_date: Option<NaiveDate>,
+}
+
+/// A spinoff of a new security.
+#[derive(Debug, Clone, Deserialize)]
+pub struct SpinOff {
+    /// The ID of the action.
+    pub id: Option<String>,
+    /// The symbol of the original security.
+    pub source_symbol: String,
+    /// The CUSIP of the original security.
+    pub source_cusip: Option<String>,
+    /// The number of original shares for every `new_rate` new shares.
+    pub source_rate: Decimal,
+    /// The symbol of the new security.
+    pub new_symbol: String,
+    /// The CUSIP of the new security.
+    pub new_cusip: Option<String>,
+    /// The number of new shares for every `source_rate` original shares.
+    pub new_rate: Decimal,
+    /// When the spinoff was processed.
+    pub process_date: Option<NaiveDate>,
+    /// The first date the original security trades without the spinoff.
+    pub ex_date: Option<NaiveDate>,
+    /// The record date of the spinoff.
+    pub record_date: Option<NaiveDate>,
+    /// When the new shares are distributed.
+    pub payable_date: Option<NaiveDate>,
+}
+
+/// An acquisition paid in cash.
+#[derive(Debug, Clone, Deserialize)]
+pub struct CashMerger {
+    /// The ID of the action.
+    pub id: Option<String>,
+    /// The symbol of the acquiring company.
+    pub acquirer_symbol: Option<String>,
+    /// The CUSIP of the acquiring company.
+    pub acquirer_cusip: Option<String>,
+    /// The symbol of the acquired company.
+    pub acquiree_symbol: String,
+    /// The CUSIP of the acquired company.
+    pub acquiree_cusip: Option<String>,
+    /// The cash paid per acquired share.
+    pub rate: Decimal,
+    /// When the merger was processed.
+    pub process_date: Option<NaiveDate>,
+    /// When the merger took effect.
+    pub effective_date: Option<NaiveDate>,
+    /// When the cash is paid.
+    pub payable_date: Option<NaiveDate>,
+}
+
+/// An acquisition paid in stock.
+#[derive(Debug, Clone, Deserialize)]
+pub struct StockMerger {
+    /// The ID of the action.
+    pub id: Option<String>,
+    /// The symbol of the acquiring company.
+    pub acquirer_symbol: String,
+    /// The CUSIP of the acquiring company.
+    pub acquirer_cusip: Option<String>,
+    /// The number of acquirer shares for every `acquiree_rate` acquired shares.
+    pub acquirer_rate: Decimal,
+    /// The symbol of the acquired company.
+    pub acquiree_symbol: String,
+    /// The CUSIP of the acquired company.
+    pub acquiree_cusip: Option<String>,
+    /// The number of acquired shares for every `acquirer_rate` acquirer shares.
+    pub acquiree_rate: Decimal,
+    /// When the merger was processed.
+    pub process_date: Option<NaiveDate>,
+    /// When the merger took effect.
+    pub effective_date: Option<NaiveDate>,
+    /// When the new shares are distributed.
+    pub payable_date: Option<NaiveDate>,
+}
+
+/// An acquisition paid in both stock and cash.
+#[derive(Debug, Clone, Deserialize)]
+pub struct StockAndCashMerger {
+    /// The ID of the action.
+    pub id: Option<String>,
+    /// The symbol of the acquiring company.
+    pub acquirer_symbol: String,
+    /// The CUSIP of the acquiring company.
+    pub acquirer_cusip: Option<String>,
+    /// The number of acquirer shares for every `acquiree_rate` acquired shares.
+    pub acquirer_rate: Decimal,
+    /// The symbol of the acquired company.
+    pub acquiree_symbol: String,
+    /// The CUSIP of the acquired company.
+    pub acquiree_cusip: Option<String>,
+    /// The number of acquired shares for every `acquirer_rate` acquirer shares.
+    pub acquiree_rate: Decimal,
+    /// The cash paid per acquired share.
+    pub rate: Decimal,
+    /// When the merger was processed.
+    pub process_date: Option<NaiveDate>,
+    /// When the merger took effect.
+    pub effective_date: Option<NaiveDate>,
+    /// When the new shares and cash are distributed.
+    pub payable_date: Option<NaiveDate>,
+}
+
+/// A redemption of a security for cash.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Redemption {
+    /// The ID of the action.
+    pub id: Option<String>,
+    /// The symbol of the security.
+    pub symbol: String,
+    /// The CUSIP of the security.
+    pub cusip: Option<String>,
+    /// The cash paid per share.
+    pub rate: Decimal,
+    /// When the redemption was processed.
+    pub process_date: Option<NaiveDate>,
+    /// When the cash is paid.
+    pub payable_date: Option<NaiveDate>,
+}
+
+/// A split of a unit into its component securities.
+#[derive(Debug, Clone, Deserialize)]
+pub struct UnitSplit {
+    /// The ID of the action.
+    pub id: Option<String>,
+    /// The symbol of the unit.
+    pub old_symbol: String,
+    /// The CUSIP of the unit.
+    pub old_cusip: Option<String>,
+    /// The number of units for every `new_rate` new shares.
+    pub old_rate: Decimal,
+    /// The symbol of the new security.
+    pub new_symbol: String,
+    /// The CUSIP of the new security.
+    pub new_cusip: Option<String>,
+    /// The number of new shares for every `old_rate` units.
+    pub new_rate: Decimal,
+    /// The symbol of the alternate security, e.g. warrants.
+    pub alternate_symbol: Option<String>,
+    /// The CUSIP of the alternate security.
+    pub alternate_cusip: Option<String>,
+    /// The number of alternate shares for every `old_rate` units.
+    pub alternate_rate: Option<Decimal>,
+    /// When the split was processed.
+    pub process_date: Option<NaiveDate>,
+    /// When the split took effect.
+    pub effective_date: Option<NaiveDate>,
+    /// When the new shares are distributed.
+    pub payable_date: Option<NaiveDate>,
+}
+
+/// The removal of a worthless security.
+#[derive(Debug, Clone, Deserialize)]
+pub struct WorthlessRemoval {
+    /// The ID of the action.
+    pub id: Option<String>,
+    /// The symbol of the security.
+    pub symbol: String,
+    /// The CUSIP of the security.
+    pub cusip: Option<String>,
+    /// When the removal was processed.
+    pub process_date: Option<NaiveDate>,
+}
+
+/// A distribution of subscription rights.
+#[derive(Debug, Clone, Deserialize)]
+pub struct RightsDistribution {
+    /// The ID of the action.
+    pub id: Option<String>,
+    /// The symbol of the original security.
+    pub source_symbol: String,
+    /// The CUSIP of the original security.
+    pub source_cusip: Option<String>,
+    /// The symbol of the rights.
+    pub new_symbol: String,
+    /// The CUSIP of the rights.
+    pub new_cusip: Option<String>,
+    /// The number of rights distributed per share.
+    pub rate: Decimal,
+    /// When the distribution was processed.
+    pub process_date: Option<NaiveDate>,
+    /// The first date the original security trades without the rights.
+    pub ex_date: Option<NaiveDate>,
+    /// The record date of the distribution.
+    pub record_date: Option<NaiveDate>,
+    /// When the rights are distributed.
+    pub payable_date: Option<NaiveDate>,
+    /// When the rights expire.
+    pub expiration_date: Option<NaiveDate>,
+}
+
+/// A change of the name or symbol of a security.
+#[derive(Debug, Clone, Deserialize)]
+pub struct NameChange {
+    /// The ID of the action.
+    pub id: Option<String>,
+    /// The previous symbol.
+    pub old_symbol: String,
+    /// The previous CUSIP.
+    pub old_cusip: Option<String>,
+    /// The new symbol.
+    pub new_symbol: String,
+    /// The new CUSIP.
+    pub new_cusip: Option<String>,
+    /// When the change was processed.
+    pub process_date: Option<NaiveDate>,
+}
+
+/// The corporate actions of a page, grouped by type.
+///
+/// Groups which are not known to this crate are kept as raw JSON in `other`.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct CorporateActionRecords {
+    /// Forward splits.
+    #[serde(deserialize_with = "null_as_default")]
+    pub forward_splits: Vec<Split>,
+    /// Reverse splits.
+    #[serde(deserialize_with = "null_as_default")]
+    pub reverse_splits: Vec<Split>,
+    /// Cash dividends.
+    #[serde(deserialize_with = "null_as_default")]
+    pub cash_dividends: Vec<CashDividend>,
+    /// Stock dividends.
+    #[serde(deserialize_with = "null_as_default")]
+    pub stock_dividends: Vec<StockDividend>,
+    /// Spinoffs.
+    #[serde(deserialize_with = "null_as_default")]
+    pub spin_offs: Vec<SpinOff>,
+    /// Cash mergers.
+    #[serde(deserialize_with = "null_as_default")]
+    pub cash_mergers: Vec<CashMerger>,
+    /// Stock mergers.
+    #[serde(deserialize_with = "null_as_default")]
+    pub stock_mergers: Vec<StockMerger>,
+    /// Mergers paid in stock and cash.
+    #[serde(deserialize_with = "null_as_default")]
+    pub stock_and_cash_mergers: Vec<StockAndCashMerger>,
+    /// Redemptions.
+    #[serde(deserialize_with = "null_as_default")]
+    pub redemptions: Vec<Redemption>,
+    /// Name changes.
+    #[serde(deserialize_with = "null_as_default")]
+    pub name_changes: Vec<NameChange>,
+    /// Unit splits.
+    #[serde(deserialize_with = "null_as_default")]
+    pub unit_splits: Vec<UnitSplit>,
+    /// Removals of worthless securities.
+    #[serde(deserialize_with = "null_as_default")]
+    pub worthless_removals: Vec<WorthlessRemoval>,
+    /// Rights distributions.
+    #[serde(deserialize_with = "null_as_default")]
+    pub rights_distributions: Vec<RightsDistribution>,
+    /// Groups which are not known to this crate, keyed by group.
+    #[serde(flatten)]
+    pub other: HashMap<String, Value>,
+}
+
+/// A page of corporate actions.
+#[derive(Debug, Clone, Deserialize)]
+pub struct CorporateActions {
+    /// The corporate actions, grouped by type.
+    #[serde(default, deserialize_with = "null_as_default")]
+    pub corporate_actions: CorporateActionRecords,
+    /// The token for the next page, if any.
+    pub next_page_token: Option<String>,
+}
+
+/// A single corporate action of any type.
+///
+/// This is the item type when paging corporate actions, which come as `(type, record)` pairs.
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub enum CorporateAction {
+    /// A forward split.
+    ForwardSplit(Split),
+    /// A reverse split.
+    ReverseSplit(Split),
+    /// A cash dividend.
+    CashDividend(CashDividend),
+    /// A stock dividend.
+    StockDividend(StockDividend),
+    /// A spinoff.
+    SpinOff(SpinOff),
+    /// A cash merger.
+    CashMerger(CashMerger),
+    /// A stock merger.
+    StockMerger(StockMerger),
+    /// A merger paid in stock and cash.
+    StockAndCashMerger(StockAndCashMerger),
+    /// A redemption.
+    Redemption(Redemption),
+    /// A name change.
+    NameChange(NameChange),
+    /// A unit split.
+    UnitSplit(UnitSplit),
+    /// A removal of a worthless security.
+    WorthlessRemoval(WorthlessRemoval),
+    /// A rights distribution.
+    RightsDistribution(RightsDistribution),
+    /// An action of a type which is not known to this crate.
+    Unknown {
+        /// The group of the action.
+        group: String,
+        /// The action itself.
+        record: Value,
+    },
+}
+
+impl<'de> Deserialize<'de> for CorporateAction {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+    {
+        fn typed<T, F, E>(record: Value, variant: F) -> Result<CorporateAction, E>
+            where
+                T: serde::de::DeserializeOwned,
+                F: FnOnce(T) -> CorporateAction,
+                E: Error,
+        {
+            serde_json::from_value(record).map(variant).map_err(E::custom)
+        }
+
+        let (group, record) = <(String, Value)>::deserialize(deserializer)?;
+        match group.as_str() {
+            "forward_splits" => typed(record, CorporateAction::ForwardSplit),
+            "reverse_splits" => typed(record, CorporateAction::ReverseSplit),
+            "cash_dividends" => typed(record, CorporateAction::CashDividend),
+            "stock_dividends" => typed(record, CorporateAction::StockDividend),
+            "spin_offs" => typed(record, CorporateAction::SpinOff),
+            "cash_mergers" => typed(record, CorporateAction::CashMerger),
+            "stock_mergers" => typed(record, CorporateAction::StockMerger),
+            "stock_and_cash_mergers" => typed(record, CorporateAction::StockAndCashMerger),
+            "redemptions" => typed(record, CorporateAction::Redemption),
+            "name_changes" => typed(record, CorporateAction::NameChange),
+            "unit_splits" => typed(record, CorporateAction::UnitSplit),
+            "worthless_removals" => typed(record, CorporateAction::WorthlessRemoval),
+            "rights_distributions" => typed(record, CorporateAction::RightsDistribution),
+            _ => {
+                Ok(CorporateAction::Unknown {
+                    group,
+                    record,
+                })
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+    use super::*;
+
+    fn split(new_rate: i64, old_rate: i64) -> Split {
+        serde_json::from_value(json!({
+            "symbol": "NVDA",
+            "new_rate": new_rate,
+            "old_rate": old_rate,
+            "ex_date": "2024-06-10",
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn split_ratios() {
+        assert_eq!(split(10, 1).ratio(), Some(Decimal::from(10)));
+        assert_eq!(split(1, 4).ratio(), Some(Decimal::new(25, 2)));
+        assert_eq!(split(1, 0).ratio(), None);
+    }
+
+    #[test]
+    fn records_by_group() {
+        let actions: CorporateActions = serde_json::from_value(json!({
+            "corporate_actions": {
+                "forward_splits": [
+                    {"symbol": "NVDA", "new_rate": 10, "old_rate": 1, "ex_date": "2024-06-10"},
+                ],
+                "cash_dividends": null,
+                "redemptions": [{"symbol": "XYZ", "rate": 25, "payable_date": "2024-03-01"}],
+                "worthless_removals": [{"symbol": "GONE", "process_date": "2024-02-01"}],
+                "some_new_actions": [{"symbol": "NEW"}],
+            },
+            "next_page_token": null,
+        }))
+        .unwrap();
+        let records = actions.corporate_actions;
+
+        assert_eq!(records.forward_splits.len(), 1);
+        assert!(records.cash_dividends.is_empty());
+        assert!(records.reverse_splits.is_empty());
+        assert_eq!(records.redemptions[0].rate, Decimal::from(25));
+        assert_eq!(records.worthless_removals[0].symbol, "GONE");
+        assert_eq!(records.other.len(), 1);
+        assert_eq!(records.other["some_new_actions"], json!([{"symbol": "NEW"}]));
+        assert_eq!(actions.next_page_token, None);
+    }
+
+    #[test]
+    fn actions_by_group() {
+        let actions: Vec<CorporateAction> = serde_json::from_value(json!([
+            ["reverse_splits", {"symbol": "XYZ", "new_rate": 1, "old_rate": 10}],
+            ["cash_dividends", {"symbol": "AAPL", "rate": "0.25", "special": false}],
+            [
+                "stock_and_cash_mergers",
+                {
+                    "acquirer_symbol": "BIG",
+                    "acquirer_rate": "0.5",
+                    "acquiree_symbol": "SMALL",
+                    "acquiree_rate": 1,
+                    "rate": "12.5",
+                },
+            ],
+            [
+                "unit_splits",
+                {
+                    "old_symbol": "SPACU",
+                    "old_rate": 1,
+                    "new_symbol": "SPAC",
+                    "new_rate": 1,
+                    "alternate_symbol": "SPACW",
+                    "alternate_rate": "0.5",
+                },
+            ],
+            [
+                "rights_distributions",
+                {
+                    "source_symbol": "ABC",
+                    "new_symbol": "ABC.RT",
+                    "rate": 1,
+                    "expiration_date": "2024-05-01",
+                },
+            ],
+        ]))
+        .unwrap();
+
+        assert!(matches!(
+            &actions[0],
+            CorporateAction::ReverseSplit(split) if split.symbol == "XYZ",
+        ));
+        assert!(matches!(&actions[1], CorporateAction::CashDividend(div) if !div.foreign));
+        match &actions[2] {
+            CorporateAction::StockAndCashMerger(merger) => {
+                assert_eq!(merger.acquirer_rate, Decimal::new(5, 1));
+                assert_eq!(merger.rate, Decimal::new(125, 1));
+            },
+            action => panic!("expected a stock and cash merger, got {:?}", action),
+        }
+        match &actions[3] {
+            CorporateAction::UnitSplit(split) => {
+                assert_eq!(split.alternate_symbol.as_deref(), Some("SPACW"));
+                assert_eq!(split.alternate_rate, Some(Decimal::new(5, 1)));
+            },
+            action => panic!("expected a unit split, got {:?}", action),
+        }
+        assert!(matches!(&actions[4], CorporateAction::RightsDistribution(_)));
+    }
+
+    #[test]
+    fn unknown_groups() {
+        let action: CorporateAction =
+            serde_json::from_value(json!(["some_new_actions", {"symbol": "NEW"}])).unwrap();
+
+        match action {
+            CorporateAction::Unknown {
+                group,
+                record,
+            } => {
+                assert_eq!(group, "some_new_actions");
+                assert_eq!(record, json!({"symbol": "NEW"}));
+            },
+            action => panic!("expected an unknown action, got {:?}", action),
+        }
+    }
+
+    #[test]
+    fn malformed_records() {
+        let err = serde_json::from_value::<CorporateAction>(json!(["forward_splits", {}]))
+            .unwrap_err();
+
+        assert!(err.to_string().contains("missing field"), "{}", err);
+    }
+}
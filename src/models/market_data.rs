@@ -0,0 +1,558 @@
+use std::collections::BTreeMap;
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Deserializer};
+use crate::models::null_as_default;
+
+string_enum! {
+    /// The side which took liquidity in a crypto trade.
+    pub enum TakerSide {
+        /// The buyer took liquidity.
+        Buy => "B",
+        /// The seller took liquidity.
+        Sell => "S",
+    }
+}
+
+/// An aggregate bar.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Bar {
+    /// The start of the bar.
+    #[serde(rename = "t")]
+    pub timestamp: DateTime<Utc>,
+    /// The opening price.
+    #[serde(rename = "o")]
+    pub open: Decimal,
+    /// The highest price.
+    #[serde(rename = "h")]
+    pub high: Decimal,
+    /// The lowest price.
+    #[serde(rename = "l")]
+    pub low: Decimal,
+    /// The closing price.
+    #[serde(rename = "c")]
+    pub close: Decimal,
+    /// The traded volume.
+    #[serde(rename = "v")]
+    pub volume: Decimal,
+    /// The number of trades.
+    #[serde(rename = "n")]
+    pub trade_count: Option<u64>,
+    /// The volume-weighted average price.
+    #[serde(rename = "vw")]
+    pub vwap: Option<Decimal>,
+}
+
+/// A page of bars for multiple symbols.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Bars {
+    /// The bars of each symbol.
+    #[serde(default, deserialize_with = "null_as_default")]
+    pub bars: BTreeMap<String, Vec<Bar>>,
+    /// The token for the next page, if any.
+    pub next_page_token: Option<String>,
+}
+
+/// A page of bars for a single symbol.
+#[derive(Debug, Clone, Deserialize)]
+pub struct SymbolBars {
+    /// The symbol of the bars.
+    pub symbol: String,
+    /// The bars.
+    #[serde(default, deserialize_with = "null_as_default")]
+    pub bars: Vec<Bar>,
+    /// The token for the next page, if any.
+    pub next_page_token: Option<String>,
+}
+
+/// A trade.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Trade {
+    /// When the trade occurred.
+    #[serde(rename = "t")]
+    pub timestamp: DateTime<Utc>,
+    /// The trade price.
+    #[serde(rename = "p")]
+    pub price: Decimal,
+    /// The trade size.
+    #[serde(rename = "s")]
+    pub size: Decimal,
+    /// The exchange code where the trade occurred.
+    #[serde(rename = "x")]
+    pub exchange: Option<String>,
+    /// The trade conditions.
+    #[serde(rename = "c", default, deserialize_with = "conditions")]
+    pub conditions: Vec<String>,
+    /// The tape of the trade.
+    #[serde(rename = "z")]
+    pub tape: Option<String>,
+    /// The ID of the trade.
+    #[serde(rename = "i")]
+    pub id: Option<u64>,
+    /// The side which took liquidity (crypto only).
+    #[serde(rename = "tks")]
+    pub taker_side: Option<TakerSide>,
+}
+
+/// A page of trades for multiple symbols.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Trades {
+    /// The trades of each symbol.
+    #[serde(default, deserialize_with = "null_as_default")]
+    pub trades: BTreeMap<String, Vec<Trade>>,
+    /// The token for the next page, if any.
+    pub next_page_token: Option<String>,
+}
+
+/// A page of trades for a single symbol.
+#[derive(Debug, Clone, Deserialize)]
+pub struct SymbolTrades {
+    /// The symbol of the trades.
+    pub symbol: String,
+    /// The trades.
+    #[serde(default, deserialize_with = "null_as_default")]
+    pub trades: Vec<Trade>,
+    /// The token for the next page, if any.
+    pub next_page_token: Option<String>,
+}
+
+/// A quote.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Quote {
+    /// When the quote was made.
+    #[serde(rename = "t")]
+    pub timestamp: DateTime<Utc>,
+    /// The bid price.
+    #[serde(rename = "bp")]
+    pub bid_price: Decimal,
+    /// The bid size.
+    #[serde(rename = "bs")]
+    pub bid_size: Decimal,
+    /// The exchange code of the bid.
+    #[serde(rename = "bx")]
+    pub bid_exchange: Option<String>,
+    /// The ask price.
+    #[serde(rename = "ap")]
+    pub ask_price: Decimal,
+    /// The ask size.
+    #[serde(rename = "as")]
+    pub ask_size: Decimal,
+    /// The exchange code of the ask.
+    #[serde(rename = "ax")]
+    pub ask_exchange: Option<String>,
+    /// The quote conditions.
+    #[serde(rename = "c", default, deserialize_with = "conditions")]
+    pub conditions: Vec<String>,
+    /// The tape of the quote.
+    #[serde(rename = "z")]
+    pub tape: Option<String>,
+}
+
+/// A page of quotes for multiple symbols.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Quotes {
+    /// The quotes of each symbol.
+    #[serde(default, deserialize_with = "null_as_default")]
+    pub quotes: BTreeMap<String, Vec<Quote>>,
+    /// The token for the next page, if any.
+    pub next_page_token: Option<String>,
+}
+
+/// A page of quotes for a single symbol.
+#[derive(Debug, Clone, Deserialize)]
+pub struct SymbolQuotes {
+    /// The symbol of the quotes.
+    pub symbol: String,
+    /// The quotes.
+    #[serde(default, deserialize_with = "null_as_default")]
+    pub quotes: Vec<Quote>,
+    /// The token for the next page, if any.
+    pub next_page_token: Option<String>,
+}
+
+/// The latest trade of each of multiple symbols.
+#[derive(Debug, Clone, Deserialize)]
+pub struct LatestTrades {
+    /// The latest trade of each symbol.
+    #[serde(default, deserialize_with = "null_as_default")]
+    pub trades: BTreeMap<String, Trade>,
+}
+
+/// The latest trade of a single symbol.
+#[derive(Debug, Clone, Deserialize)]
+pub struct LatestTrade {
+    /// The symbol of the trade.
+    pub symbol: String,
+    /// The latest trade.
+    pub trade: Trade,
+}
+
+/// The latest quote of each of multiple symbols.
+#[derive(Debug, Clone, Deserialize)]
+pub struct LatestQuotes {
+    /// The latest quote of each symbol.
+    #[serde(default, deserialize_with = "null_as_default")]
+    pub quotes: BTreeMap<String, Quote>,
+}
+
+/// The latest quote of a single symbol.
+#[derive(Debug, Clone, Deserialize)]
+pub struct LatestQuote {
+    /// The symbol of the quote.
+    pub symbol: String,
+    /// The latest quote.
+    pub quote: Quote,
+}
+
+/// The latest minute bar of each of multiple symbols.
+#[derive(Debug, Clone, Deserialize)]
+pub struct LatestBars {
+    /// The latest bar of each symbol.
+    #[serde(default, deserialize_with = "null_as_default")]
+    pub bars: BTreeMap<String, Bar>,
+}
+
+/// The latest minute bar of a single symbol.
+#[derive(Debug, Clone, Deserialize)]
+pub struct LatestBar {
+    /// The symbol of the bar.
+    pub symbol: String,
+    /// The latest bar.
+    pub bar: Bar,
+}
+
+/// A snapshot of the latest market data of a symbol.
+///
+/// Any part may be missing, e.g. for a symbol which has not traded recently.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Snapshot {
+    /// The latest trade.
+    pub latest_trade: Option<Trade>,
+    /// The latest quote.
+    pub latest_quote: Option<Quote>,
+    /// The latest minute bar.
+    pub minute_bar: Option<Bar>,
+    /// The bar of the current trading day.
+    pub daily_bar: Option<Bar>,
+    /// The bar of the previous trading day.
+    pub prev_daily_bar: Option<Bar>,
+}
+
+/// The snapshots of multiple crypto symbols.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Snapshots {
+    /// The snapshot of each symbol.
+    #[serde(default, deserialize_with = "null_as_default")]
+    pub snapshots: BTreeMap<String, Snapshot>,
+}
+
+/// A price level of an orderbook.
+#[derive(Debug, Clone, Deserialize)]
+pub struct OrderbookEntry {
+    /// The price of the level.
+    #[serde(rename = "p")]
+    pub price: Decimal,
+    /// The size available at the price.
+    #[serde(rename = "s")]
+    pub size: Decimal,
+}
+
+/// An orderbook.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Orderbook {
+    /// When the orderbook was taken.
+    #[serde(rename = "t")]
+    pub timestamp: DateTime<Utc>,
+    /// The bids, best first.
+    #[serde(rename = "b", default, deserialize_with = "null_as_default")]
+    pub bids: Vec<OrderbookEntry>,
+    /// The asks, best first.
+    #[serde(rename = "a", default, deserialize_with = "null_as_default")]
+    pub asks: Vec<OrderbookEntry>,
+}
+
+/// The latest orderbook of each of multiple symbols.
+#[derive(Debug, Clone, Deserialize)]
+pub struct LatestOrderbooks {
+    /// The latest orderbook of each symbol.
+    #[serde(default, deserialize_with = "null_as_default")]
+    pub orderbooks: BTreeMap<String, Orderbook>,
+}
+
+/// The greeks of an option contract.
+#[derive(Debug, Clone, Copy, Deserialize)]
+pub struct Greeks {
+    /// The sensitivity of the price to the underlying price.
+    pub delta: f64,
+    /// The sensitivity of delta to the underlying price.
+    pub gamma: f64,
+    /// The sensitivity of the price to the interest rate.
+    pub rho: f64,
+    /// The sensitivity of the price to time.
+    pub theta: f64,
+    /// The sensitivity of the price to volatility.
+    pub vega: f64,
+}
+
+/// A snapshot of the latest market data of an option contract.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OptionSnapshot {
+    /// The latest trade.
+    pub latest_trade: Option<Trade>,
+    /// The latest quote.
+    pub latest_quote: Option<Quote>,
+    /// The latest minute bar.
+    pub minute_bar: Option<Bar>,
+    /// The bar of the current trading day.
+    pub daily_bar: Option<Bar>,
+    /// The bar of the previous trading day.
+    pub prev_daily_bar: Option<Bar>,
+    /// The greeks, if they could be computed.
+    pub greeks: Option<Greeks>,
+    /// The implied volatility, if it could be computed.
+    pub implied_volatility: Option<f64>,
+}
+
+/// A page of option snapshots.
+#[derive(Debug, Clone, Deserialize)]
+pub struct OptionSnapshots {
+    /// The snapshot of each contract, keyed by contract symbol.
+    #[serde(default, deserialize_with = "null_as_default")]
+    pub snapshots: BTreeMap<String, OptionSnapshot>,
+    /// The token for the next page, if any.
+    pub next_page_token: Option<String>,
+}
+
+/// Deserialize trade or quote conditions, which options data sends as a single string.
+fn conditions<'de, D>(deserializer: D) -> Result<Vec<String>, D::Error>
+    where
+        D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Conditions {
+        One(String),
+        Many(Vec<String>),
+    }
+
+    Ok(match Option::<Conditions>::deserialize(deserializer)? {
+        None => Vec::new(),
+        Some(Conditions::One(condition)) => vec![condition],
+        Some(Conditions::Many(conditions)) => conditions,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+    use super::*;
+
+    fn bar() -> serde_json::Value {
+        json!({
+            "t": "2024-01-02T05:00:00Z",
+            "o": 187.15,
+            "h": 188.44,
+            "l": 183.885,
+            "c": 185.64,
+            "v": 82488674,
+            "n": 1009074,
+            "vw": 185.894,
+        })
+    }
+
+    #[test]
+    fn bars_decode() {
+        let bars: Bars = serde_json::from_value(json!({
+            "bars": {"AAPL": [bar()]},
+            "next_page_token": "QUFQTHxEfDIwMjQtMDEtMDJUMDU6MDA6MDAuMDAwMDAwMDAwWg==",
+        }))
+        .unwrap();
+
+        let bar = &bars.bars["AAPL"][0];
+        assert_eq!(bar.open, Decimal::new(18715, 2));
+        assert_eq!(bar.low, Decimal::new(183885, 3));
+        assert_eq!(bar.trade_count, Some(1009074));
+        assert!(bars.next_page_token.is_some());
+    }
+
+    #[test]
+    fn empty_bar_pages_decode() {
+        let bars: Bars = serde_json::from_value(json!({"bars": null, "next_page_token": null}))
+            .unwrap();
+        assert!(bars.bars.is_empty());
+
+        let bars: SymbolBars = serde_json::from_value(json!({
+            "symbol": "AAPL",
+            "bars": null,
+            "next_page_token": null,
+        }))
+        .unwrap();
+        assert!(bars.bars.is_empty());
+    }
+
+    #[test]
+    fn trade_conditions_decode_from_lists_and_strings() {
+        let trade = json!({"t": "2024-01-02T14:30:00.123456789Z", "p": 187.15, "s": 100});
+
+        let mut with_list = trade.clone();
+        with_list["c"] = json!(["@", "F"]);
+        let decoded: Trade = serde_json::from_value(with_list).unwrap();
+        assert_eq!(decoded.conditions, ["@", "F"]);
+
+        let mut with_string = trade.clone();
+        with_string["c"] = json!("I");
+        let decoded: Trade = serde_json::from_value(with_string).unwrap();
+        assert_eq!(decoded.conditions, ["I"]);
+
+        let mut with_null = trade.clone();
+        with_null["c"] = json!(null);
+        let decoded: Trade = serde_json::from_value(with_null).unwrap();
+        assert!(decoded.conditions.is_empty());
+
+        let decoded: Trade = serde_json::from_value(trade).unwrap();
+        assert!(decoded.conditions.is_empty());
+        assert_eq!(decoded.size, Decimal::ONE_HUNDRED);
+    }
+
+    #[test]
+    fn quotes_decode() {
+        let quotes: SymbolQuotes = serde_json::from_value(json!({
+            "symbol": "AAPL",
+            "quotes": [{
+                "t": "2024-01-02T14:30:00Z",
+                "bp": 187.1,
+                "bs": 2,
+                "bx": "V",
+                "ap": 187.2,
+                "as": 3,
+                "ax": "Q",
+                "c": ["R"],
+                "z": "C",
+            }],
+            "next_page_token": null,
+        }))
+        .unwrap();
+
+        let quote = &quotes.quotes[0];
+        assert_eq!(quote.bid_price, Decimal::new(1871, 1));
+        assert_eq!(quote.ask_size, Decimal::new(3, 0));
+        assert_eq!(quote.ask_exchange.as_deref(), Some("Q"));
+        assert_eq!(quote.conditions, ["R"]);
+    }
+
+    #[test]
+    fn latest_data_decode() {
+        let latest: LatestBars = serde_json::from_value(json!({"bars": {"AAPL": bar()}})).unwrap();
+        assert_eq!(latest.bars["AAPL"].close, Decimal::new(18564, 2));
+
+        let latest: LatestTrade = serde_json::from_value(json!({
+            "symbol": "AAPL",
+            "trade": {
+                "t": "2024-01-02T14:30:00Z",
+                "p": 187.15,
+                "s": 5,
+                "x": "V",
+                "i": 52983525029461u64,
+            },
+        }))
+        .unwrap();
+        assert_eq!(latest.trade.id, Some(52983525029461));
+        assert_eq!(latest.trade.exchange.as_deref(), Some("V"));
+    }
+
+    #[test]
+    fn snapshots_decode_with_missing_parts() {
+        let snapshots: BTreeMap<String, Snapshot> = serde_json::from_value(json!({
+            "AAPL": {
+                "latestTrade": {"t": "2024-01-02T20:59:59Z", "p": 185.64, "s": 100},
+                "dailyBar": bar(),
+                "prevDailyBar": bar(),
+            },
+            "NEWCO": {},
+        }))
+        .unwrap();
+
+        let aapl = &snapshots["AAPL"];
+        assert_eq!(aapl.latest_trade.as_ref().unwrap().price, Decimal::new(18564, 2));
+        assert!(aapl.latest_quote.is_none());
+        assert!(aapl.minute_bar.is_none());
+        assert!(aapl.daily_bar.is_some() && aapl.prev_daily_bar.is_some());
+        assert!(snapshots["NEWCO"].latest_trade.is_none());
+    }
+
+    #[test]
+    fn orderbooks_decode() {
+        let orderbooks: LatestOrderbooks = serde_json::from_value(json!({
+            "orderbooks": {
+                "BTC/USD": {
+                    "t": "2024-01-02T14:30:00.123Z",
+                    "b": [{"p": 45000.5, "s": 0.25}, {"p": 45000, "s": 1.5}],
+                    "a": null,
+                },
+            },
+        }))
+        .unwrap();
+
+        let orderbook = &orderbooks.orderbooks["BTC/USD"];
+        assert_eq!(orderbook.bids.len(), 2);
+        assert_eq!(orderbook.bids[0].price, Decimal::new(450005, 1));
+        assert_eq!(orderbook.bids[1].size, Decimal::new(15, 1));
+        assert!(orderbook.asks.is_empty());
+    }
+
+    #[test]
+    fn crypto_trades_decode_taker_side() {
+        let trade: Trade = serde_json::from_value(json!({
+            "t": "2024-01-02T14:30:00Z",
+            "p": 45000.5,
+            "s": 0.001,
+            "i": 123,
+            "tks": "S",
+        }))
+        .unwrap();
+
+        assert_eq!(trade.taker_side, Some(TakerSide::Sell));
+        assert_eq!(trade.size, Decimal::new(1, 3));
+    }
+
+    #[test]
+    fn option_snapshots_decode() {
+        let snapshots: OptionSnapshots = serde_json::from_value(json!({
+            "snapshots": {
+                "AAPL240119C00190000": {
+                    "latestQuote": {
+                        "t": "2024-01-02T20:59:59Z",
+                        "bp": 1.2,
+                        "bs": 10,
+                        "bx": "C",
+                        "ap": 1.25,
+                        "as": 12,
+                        "ax": "X",
+                        "c": "A",
+                    },
+                    "greeks": {
+                        "delta": 0.4,
+                        "gamma": 0.05,
+                        "rho": 0.01,
+                        "theta": -0.2,
+                        "vega": 0.1,
+                    },
+                    "impliedVolatility": 0.25,
+                },
+                "AAPL240119P00190000": {},
+            },
+            "next_page_token": null,
+        }))
+        .unwrap();
+
+        let call = &snapshots.snapshots["AAPL240119C00190000"];
+        assert_eq!(call.latest_quote.as_ref().unwrap().conditions, ["A"]);
+        assert_eq!(call.greeks.unwrap().theta, -0.2);
+        assert_eq!(call.implied_volatility, Some(0.25));
+
+        let put = &snapshots.snapshots["AAPL240119P00190000"];
+        assert!(put.greeks.is_none() && put.implied_volatility.is_none());
+    }
+}
@@ -0,0 +1,135 @@
+//! Typed responses from the Alpaca trading API.
+//!
+//! Decimal strings are parsed as `Decimal` and timestamps as `DateTime<Utc>`. Enumerations fall
+//! back to an `Unknown` variant for values this crate does not know about yet.
+
+/// Declare an enumeration of string values sent by Alpaca.
+///
+/// Values not listed are kept in an `Unknown` variant so that new server values do not break
+/// deserialization. Additional spellings of a value may be given after a `|`.
+macro_rules! string_enum {
+    (
+        $(#[$meta:meta])*
+        pub enum $name:ident {
+            $(
+                $(#[$variant_meta:meta])*
+                $variant:ident => $value:literal $(| $alias:literal)*,
+            )*
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+        pub enum $name {
+            $(
+                $(#[$variant_meta])*
+                $variant,
+            )*
+            /// A value which is not known to this crate.
+            Unknown(String),
+        }
+
+        impl $name {
+            /// The string representation of the value.
+            pub fn as_str(&self) -> &str {
+                match self {
+                    $($name::$variant => $value,)*
+                    $name::Unknown(value) => value,
+                }
+            }
+        }
+
+        impl From<&str> for $name {
+            fn from(value: &str) -> Self {
+                match value {
+                    $($value $(| $alias)* => $name::$variant,)*
+                    _ => $name::Unknown(value.into()),
+                }
+            }
+        }
+
+        impl std::fmt::Display for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
+
+        impl serde::Serialize for $name {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+                where
+                    S: serde::Serializer,
+            {
+                serializer.serialize_str(self.as_str())
+            }
+        }
+
+        impl<'de> serde::Deserialize<'de> for $name {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+                where
+                    D: serde::Deserializer<'de>,
+            {
+                let value = String::deserialize(deserializer)?;
+                Ok(value.as_str().into())
+            }
+        }
+
+        impl crate::params::ParamValue<'static> for $name {
+            fn as_value(&self) -> std::borrow::Cow<'static, str> {
+                self.as_str().to_owned().into()
+            }
+        }
+
+        impl crate::params::ParamValue<'static> for &$name {
+            fn as_value(&self) -> std::borrow::Cow<'static, str> {
+                (*self).as_value()
+            }
+        }
+    };
+}
+
+mod account;
+mod activity;
+mod asset;
+mod calendar;
+mod corporate_action;
+mod market_data;
+mod news;
+mod option_contract;
+mod order;
+mod portfolio;
+mod position;
+mod watchlist;
+
+pub use self::account::{
+    Account, AccountConfigurations, AccountStatus, DayTradeCheck, TradeConfirmEmail,
+};
+pub use self::activity::{Activity, ActivityType, NonTradeActivity, TradeActivity};
+pub use self::asset::{Asset, AssetAttribute, AssetClass, AssetStatus, Exchange};
+pub use self::calendar::{CalendarDay, Clock, TradingCalendar};
+pub use self::corporate_action::{
+    Announcement, AnnouncementType, CashDividend, CashMerger, CorporateAction,
+    CorporateActionRecords, CorporateActions, NameChange, Redemption, RightsDistribution, SpinOff,
+    Split, StockAndCashMerger, StockDividend, StockMerger, UnitSplit, WorthlessRemoval,
+};
+pub use self::market_data::{
+    Bar, Bars, Greeks, LatestBar, LatestBars, LatestOrderbooks, LatestQuote, LatestQuotes,
+    LatestTrade, LatestTrades, OptionSnapshot, OptionSnapshots, Orderbook, OrderbookEntry, Quote,
+    Quotes, Snapshot, Snapshots, SymbolBars, SymbolQuotes, SymbolTrades, TakerSide, Trade, Trades,
+};
+pub use self::news::{ImageSize, News, NewsArticle, NewsImage};
+pub use self::option_contract::{OptionContract, OptionStatus, OptionStyle, OptionType};
+pub use self::order::{Order, OrderClass, OrderSide, OrderStatus, OrderType, PositionIntent, TimeInForce};
+pub use self::portfolio::{PortfolioHistory, PortfolioHistoryRow};
+pub use self::position::{Position, PositionSide};
+pub use self::watchlist::Watchlist;
+
+/// Deserialize a `null` value as the default value of a type.
+///
+/// Alpaca sends `null` rather than an empty list or map for pages without results.
+fn null_as_default<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+        T: serde::Deserialize<'de> + Default,
+{
+    let value = <Option<T> as serde::Deserialize>::deserialize(deserializer)?;
+    Ok(value.unwrap_or_default())
+}
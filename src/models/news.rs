@@ -0,0 +1,134 @@
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use crate::models::null_as_default;
+
+string_enum! {
+    /// The size of a news image.
+    pub enum ImageSize {
+        /// A large image.
+        Large => "large",
+        /// A small image.
+        Small => "small",
+        /// A thumbnail.
+        Thumb => "thumb",
+    }
+}
+
+/// An image of a news article.
+#[derive(Debug, Clone, Deserialize)]
+pub struct NewsImage {
+    /// The size of the image.
+    pub size: ImageSize,
+    /// The URL of the image.
+    pub url: String,
+}
+
+/// A news article.
+#[derive(Debug, Clone, Deserialize)]
+pub struct NewsArticle {
+    /// The ID of the article.
+    pub id: u64,
+    /// The headline of the article.
+    pub headline: String,
+    /// The author of the article.
+    pub author: String,
+    /// When the article was published.
+    pub created_at: DateTime<Utc>,
+    /// When the article was last updated.
+    pub updated_at: DateTime<Utc>,
+    /// A summary of the article.
+    pub summary: String,
+    /// The content of the article, which may contain HTML; empty unless requested.
+    #[serde(default)]
+    pub content: String,
+    /// The images of the article.
+    #[serde(default, deserialize_with = "null_as_default")]
+    pub images: Vec<NewsImage>,
+    /// The symbols the article is about.
+    #[serde(default, deserialize_with = "null_as_default")]
+    pub symbols: Vec<String>,
+    /// The source of the article.
+    pub source: String,
+    /// The URL of the article, if any.
+    pub url: Option<String>,
+}
+
+/// A page of news articles.
+#[derive(Debug, Clone, Deserialize)]
+pub struct News {
+    /// The articles.
+    #[serde(default, deserialize_with = "null_as_default")]
+    pub news: Vec<NewsArticle>,
+    /// The token for the next page, if any.
+    pub next_page_token: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+    use super::*;
+
+    #[test]
+    fn articles() {
+        let news: News = serde_json::from_value(json!({
+            "news": [
+                {
+                    "id": 24803233,
+                    "headline": "Apple Announces Earnings",
+                    "author": "Benzinga Newsdesk",
+                    "created_at": "2024-01-19T14:30:00Z",
+                    "updated_at": "2024-01-19T14:31:00Z",
+                    "summary": "",
+                    "content": "<p>Apple reported...</p>",
+                    "images": [
+                        {"size": "large", "url": "https://example.com/large.jpg"},
+                        {"size": "huge", "url": "https://example.com/huge.jpg"},
+                    ],
+                    "symbols": ["AAPL"],
+                    "source": "benzinga",
+                    "url": "https://example.com/apple",
+                },
+                {
+                    "id": 24803234,
+                    "headline": "Markets Open",
+                    "author": "",
+                    "created_at": "2024-01-19T14:30:00Z",
+                    "updated_at": "2024-01-19T14:30:00Z",
+                    "summary": "",
+                    "images": null,
+                    "symbols": null,
+                    "source": "benzinga",
+                    "url": null,
+                },
+            ],
+            "next_page_token": "MTcwNTY3NDYwMDAwMDAwMDAwMHwyNDgwMzIzNA==",
+        }))
+        .unwrap();
+
+        assert_eq!(news.news.len(), 2);
+        let article = &news.news[0];
+        assert_eq!(article.id, 24803233);
+        assert_eq!(article.images[0].size, ImageSize::Large);
+        assert_eq!(article.images[1].size, ImageSize::Unknown("huge".into()));
+        assert_eq!(article.symbols, ["AAPL"]);
+
+        let article = &news.news[1];
+        assert!(article.content.is_empty());
+        assert!(article.images.is_empty());
+        assert!(article.symbols.is_empty());
+        assert_eq!(article.url, None);
+        assert!(news.next_page_token.is_some());
+    }
+
+    #[test]
+    fn empty_pages() {
+        let news: News = serde_json::from_value(json!({
+            "news": null,
+            "next_page_token": null,
+        }))
+        .unwrap();
+
+        assert!(news.news.is_empty());
+        assert_eq!(news.next_page_token, None);
+    }
+}
@@ -0,0 +1,75 @@
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+use serde::Deserialize;
+
+string_enum! {
+    /// The type of an option contract.
+    pub enum OptionType {
+        /// A call option.
+        Call => "call",
+        /// A put option.
+        Put => "put",
+    }
+}
+
+string_enum! {
+    /// The exercise style of an option contract.
+    pub enum OptionStyle {
+        /// The option may be exercised at any time before expiration.
+        American => "american",
+        /// The option may only be exercised at expiration.
+        European => "european",
+    }
+}
+
+string_enum! {
+    /// The status of an option contract.
+    pub enum OptionStatus {
+        /// The contract is active.
+        Active => "active",
+        /// The contract is inactive.
+        Inactive => "inactive",
+    }
+}
+
+/// An option contract.
+#[derive(Debug, Clone, Deserialize)]
+pub struct OptionContract {
+    /// The ID of the contract.
+    pub id: String,
+    /// The OCC symbol of the contract.
+    pub symbol: String,
+    /// The name of the contract.
+    pub name: String,
+    /// The status of the contract.
+    pub status: OptionStatus,
+    /// Whether the contract is tradable on Alpaca.
+    pub tradable: bool,
+    /// The expiration date of the contract.
+    pub expiration_date: NaiveDate,
+    /// The root symbol of the contract.
+    pub root_symbol: Option<String>,
+    /// The symbol of the underlying asset.
+    pub underlying_symbol: String,
+    /// The ID of the underlying asset.
+    pub underlying_asset_id: String,
+    /// The type of the contract.
+    #[serde(rename = "type")]
+    pub option_type: OptionType,
+    /// The exercise style of the contract.
+    pub style: OptionStyle,
+    /// The strike price of the contract.
+    pub strike_price: Decimal,
+    /// The number of underlying shares per contract.
+    pub multiplier: Option<Decimal>,
+    /// The contract size.
+    pub size: Option<Decimal>,
+    /// The open interest.
+    pub open_interest: Option<Decimal>,
+    /// The date of the open interest.
+    pub open_interest_date: Option<NaiveDate>,
+    /// The closing price.
+    pub close_price: Option<Decimal>,
+    /// The date of the closing price.
+    pub close_price_date: Option<NaiveDate>,
+}
@@ -0,0 +1,251 @@
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::Deserialize;
+use crate::models::AssetClass;
+
+string_enum! {
+    /// The side of an order.
+    pub enum OrderSide {
+        /// Buy the asset.
+        Buy => "buy",
+        /// Sell the asset.
+        Sell => "sell",
+    }
+}
+
+string_enum! {
+    /// The type of an order.
+    pub enum OrderType {
+        /// Execute at the best available price.
+        Market => "market",
+        /// Execute at `limit_price` or better.
+        Limit => "limit",
+        /// Become a market order once `stop_price` is reached.
+        Stop => "stop",
+        /// Become a limit order once `stop_price` is reached.
+        StopLimit => "stop_limit",
+        /// A stop order whose stop price trails the market.
+        TrailingStop => "trailing_stop",
+    }
+}
+
+string_enum! {
+    /// How long an order remains in effect.
+    pub enum TimeInForce {
+        /// Valid for the current trading day only.
+        Day => "day",
+        /// Good until canceled.
+        Gtc => "gtc",
+        /// Execute in the market opening auction only.
+        Opg => "opg",
+        /// Execute in the market closing auction only.
+        Cls => "cls",
+        /// Immediate or cancel.
+        Ioc => "ioc",
+        /// Fill or kill.
+        Fok => "fok",
+    }
+}
+
+string_enum! {
+    /// The class of an order.
+    pub enum OrderClass {
+        /// A single order.
+        Simple => "simple" | "",
+        /// An entry order with both a take-profit and a stop-loss leg.
+        Bracket => "bracket",
+        /// One-cancels-other: a take-profit and a stop-loss leg for an existing position.
+        Oco => "oco",
+        /// One-triggers-other: an entry order with either a take-profit or a stop-loss leg.
+        Oto => "oto",
+        /// A multi-leg options order.
+        Mleg => "mleg",
+    }
+}
+
+string_enum! {
+    /// The status of an order.
+    pub enum OrderStatus {
+        /// The order has been received and routed to exchanges.
+        New => "new",
+        /// The order has been partially filled.
+        PartiallyFilled => "partially_filled",
+        /// The order has been completely filled.
+        Filled => "filled",
+        /// The order is done for the day.
+        DoneForDay => "done_for_day",
+        /// The order has been canceled.
+        Canceled => "canceled",
+        /// The order has expired.
+        Expired => "expired",
+        /// The order was replaced by another order.
+        Replaced => "replaced",
+        /// The order is waiting to be canceled.
+        PendingCancel => "pending_cancel",
+        /// The order is waiting to be replaced.
+        PendingReplace => "pending_replace",
+        /// The order has been received but not yet routed.
+        PendingNew => "pending_new",
+        /// The order has been received by exchanges but not yet accepted.
+        AcceptedForBidding => "accepted_for_bidding",
+        /// The order has been received but is not yet active.
+        Accepted => "accepted",
+        /// The order has been stopped and a trade is guaranteed.
+        Stopped => "stopped",
+        /// The order has been rejected.
+        Rejected => "rejected",
+        /// The order has been suspended.
+        Suspended => "suspended",
+        /// The order has been completed for the day and is being calculated.
+        Calculated => "calculated",
+        /// The order is held until its trigger is reached.
+        Held => "held",
+    }
+}
+
+impl OrderStatus {
+    /// Whether the order can no longer be filled.
+    ///
+    /// Orders which are `done_for_day` are not terminal, since multi-day orders such as `gtc`
+    /// orders resume in the next session.
+    pub fn is_terminal(&self) -> bool {
+        matches!(
+            self,
+            OrderStatus::Filled
+                | OrderStatus::Canceled
+                | OrderStatus::Expired
+                | OrderStatus::Replaced
+                | OrderStatus::Rejected,
+        )
+    }
+}
+
+string_enum! {
+    /// The intent of an options order with respect to the position.
+    pub enum PositionIntent {
+        /// Buy to open a long position.
+        BuyToOpen => "buy_to_open",
+        /// Buy to close a short position.
+        BuyToClose => "buy_to_close",
+        /// Sell to open a short position.
+        SellToOpen => "sell_to_open",
+        /// Sell to close a long position.
+        SellToClose => "sell_to_close",
+    }
+}
+
+/// An order.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Order {
+    /// The ID of the order.
+    pub id: String,
+    /// The client-assigned ID of the order.
+    pub client_order_id: String,
+    /// When the order was created.
+    pub created_at: DateTime<Utc>,
+    /// When the order was last updated.
+    pub updated_at: Option<DateTime<Utc>>,
+    /// When the order was submitted.
+    pub submitted_at: Option<DateTime<Utc>>,
+    /// When the order was filled.
+    pub filled_at: Option<DateTime<Utc>>,
+    /// When the order expired.
+    pub expired_at: Option<DateTime<Utc>>,
+    /// When the order was canceled.
+    pub canceled_at: Option<DateTime<Utc>>,
+    /// When the order failed.
+    pub failed_at: Option<DateTime<Utc>>,
+    /// When the order was replaced.
+    pub replaced_at: Option<DateTime<Utc>>,
+    /// The ID of the order which replaced this one.
+    pub replaced_by: Option<String>,
+    /// The ID of the order this one replaces.
+    pub replaces: Option<String>,
+    /// The ID of the asset.
+    pub asset_id: String,
+    /// The symbol of the asset.
+    pub symbol: String,
+    /// The class of the asset.
+    pub asset_class: AssetClass,
+    /// The dollar amount to trade.
+    pub notional: Option<Decimal>,
+    /// The number of shares to trade.
+    pub qty: Option<Decimal>,
+    /// The number of shares filled.
+    pub filled_qty: Decimal,
+    /// The average fill price.
+    pub filled_avg_price: Option<Decimal>,
+    /// The class of the order.
+    pub order_class: OrderClass,
+    /// The type of the order.
+    #[serde(rename = "type")]
+    pub order_type: OrderType,
+    /// The side of the order.
+    pub side: OrderSide,
+    /// How long the order remains in effect.
+    pub time_in_force: TimeInForce,
+    /// The limit price.
+    pub limit_price: Option<Decimal>,
+    /// The stop price.
+    pub stop_price: Option<Decimal>,
+    /// The status of the order.
+    pub status: OrderStatus,
+    /// Whether the order may execute in pre- and post-market sessions.
+    pub extended_hours: bool,
+    /// The legs of a multi-leg order when queried with `nested`.
+    pub legs: Option<Vec<Order>>,
+    /// The percentage offset of a trailing stop order.
+    pub trail_percent: Option<Decimal>,
+    /// The dollar offset of a trailing stop order.
+    pub trail_price: Option<Decimal>,
+    /// The high-water mark of a trailing stop order.
+    pub hwm: Option<Decimal>,
+    /// The intent of an options order.
+    pub position_intent: Option<PositionIntent>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn terminal_statuses() {
+        let statuses = [
+            (OrderStatus::New, false),
+            (OrderStatus::PartiallyFilled, false),
+            (OrderStatus::Filled, true),
+            (OrderStatus::DoneForDay, false),
+            (OrderStatus::Canceled, true),
+            (OrderStatus::Expired, true),
+            (OrderStatus::Replaced, true),
+            (OrderStatus::PendingCancel, false),
+            (OrderStatus::PendingReplace, false),
+            (OrderStatus::PendingNew, false),
+            (OrderStatus::AcceptedForBidding, false),
+            (OrderStatus::Accepted, false),
+            (OrderStatus::Stopped, false),
+            (OrderStatus::Rejected, true),
+            (OrderStatus::Suspended, false),
+            (OrderStatus::Calculated, false),
+            (OrderStatus::Held, false),
+            (OrderStatus::Unknown("unheard_of".into()), false),
+        ];
+
+        for (status, terminal) in statuses {
+            assert_eq!(status.is_terminal(), terminal, "{}", status);
+        }
+    }
+
+    #[test]
+    fn string_enums_keep_unknown_values() {
+        let status: OrderStatus = serde_json::from_str("\"unheard_of\"").unwrap();
+        assert_eq!(status, OrderStatus::Unknown("unheard_of".into()));
+        assert_eq!(serde_json::to_string(&status).unwrap(), "\"unheard_of\"");
+    }
+
+    #[test]
+    fn empty_order_class_is_simple() {
+        let class: OrderClass = serde_json::from_str("\"\"").unwrap();
+        assert_eq!(class, OrderClass::Simple);
+    }
+}
@@ -0,0 +1,306 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use rust_decimal::Decimal;
+use rust_decimal::prelude::ToPrimitive;
+use serde::{Deserialize, Deserializer};
+use serde::de::Error;
+use crate::models::calendar::exchange_date;
+use crate::models::null_as_default;
+
+/// The number of trading days per year used to annualize daily statistics.
+const TRADING_DAYS_PER_YEAR: f64 = 252.;
+
+/// The history of the value of an account.
+///
+/// Alpaca sends the history as parallel columns; use [`PortfolioHistory::rows`] to walk it point
+/// by point. Points without data (e.g. before the account was funded) have no equity.
+#[derive(Debug, Clone, Deserialize)]
+pub struct PortfolioHistory {
+    /// The time of each point.
+    #[serde(deserialize_with = "unix_timestamps")]
+    pub timestamp: Vec<DateTime<Utc>>,
+    /// The equity at each point.
+    #[serde(default, deserialize_with = "null_as_default")]
+    pub equity: Vec<Option<Decimal>>,
+    /// The profit or loss at each point since the base value.
+    #[serde(default, deserialize_with = "null_as_default")]
+    pub profit_loss: Vec<Option<Decimal>>,
+    /// The profit or loss at each point as a fraction of the base value.
+    #[serde(default, deserialize_with = "null_as_default")]
+    pub profit_loss_pct: Vec<Option<Decimal>>,
+    /// The equity the profit or loss is relative to.
+    pub base_value: Option<Decimal>,
+    /// The date of the base value.
+    pub base_value_asof: Option<NaiveDate>,
+    /// The time frame between points.
+    pub timeframe: String,
+}
+
+/// A single point of a portfolio history.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PortfolioHistoryRow {
+    /// The time of the point.
+    pub timestamp: DateTime<Utc>,
+    /// The equity at the point.
+    pub equity: Option<Decimal>,
+    /// The profit or loss since the base value.
+    pub profit_loss: Option<Decimal>,
+    /// The profit or loss as a fraction of the base value.
+    pub profit_loss_pct: Option<Decimal>,
+}
+
+impl PortfolioHistory {
+    /// The points of the history, in time order.
+    pub fn rows(&self) -> impl Iterator<Item = PortfolioHistoryRow> + '_ {
+        let column = |values: &'_ [Option<Decimal>], index| values.get(index).copied().flatten();
+        self.timestamp
+            .iter()
+            .enumerate()
+            .map(move |(index, &timestamp)| {
+                PortfolioHistoryRow {
+                    timestamp,
+                    equity: column(&self.equity, index),
+                    profit_loss: column(&self.profit_loss, index),
+                    profit_loss_pct: column(&self.profit_loss_pct, index),
+                }
+            })
+    }
+
+    /// The largest peak-to-trough decline of the value of the account, as a fraction of the
+    /// peak.
+    ///
+    /// The decline is measured on the compounded returns between points (see
+    /// [`PortfolioHistory::daily_returns`]) so that withdrawals are not counted as losses.
+    /// Returns `None` if the history has fewer than two points with equity and profit or loss.
+    pub fn max_drawdown(&self) -> Option<f64> {
+        let mut value = 1.;
+        let mut peak = value;
+        let mut max_drawdown = None;
+        for (_, point_return) in self.point_returns() {
+            value *= 1. + point_return;
+            peak = f64::max(peak, value);
+            let drawdown = (peak - value) / peak;
+            max_drawdown = Some(f64::max(max_drawdown.unwrap_or(0.), drawdown));
+        }
+        max_drawdown
+    }
+
+    /// The return of each trading day.
+    ///
+    /// The return between two points is the change in `profit_loss` relative to the equity at
+    /// the first point, so deposits and withdrawals are not counted as gains or losses. The
+    /// returns between the points ending on an exchange date are compounded into the return of
+    /// that date; the first point of the history has no return.
+    ///
+    /// This assumes `profit_loss` accumulates over the whole history, as it does for daily
+    /// histories. Intraday histories should be queried with `PnlReset::NoReset` since a per-day
+    /// reset would be counted as a loss or gain of the day's profit or loss.
+    pub fn daily_returns(&self) -> Vec<(NaiveDate, f64)> {
+        let mut returns: Vec<(NaiveDate, f64)> = Vec::new();
+        for (timestamp, point_return) in self.point_returns() {
+            let date = exchange_date(timestamp);
+            match returns.last_mut() {
+                Some((last, daily_return)) if *last == date => {
+                    *daily_return = (1. + *daily_return) * (1. + point_return) - 1.;
+                },
+                _ => returns.push((date, point_return)),
+            }
+        }
+        returns
+    }
+
+    /// The annualized volatility of the daily returns.
+    ///
+    /// Returns `None` if there are fewer than two daily returns.
+    pub fn volatility(&self) -> Option<f64> {
+        let returns = self.return_values();
+        std_dev(&returns).map(|std_dev| std_dev * TRADING_DAYS_PER_YEAR.sqrt())
+    }
+
+    /// The annualized Sharpe ratio of the daily returns given an annual risk-free rate.
+    ///
+    /// Returns `None` if there are fewer than two daily returns or they do not vary.
+    pub fn sharpe_ratio(&self, risk_free_rate: f64) -> Option<f64> {
+        let returns = self.return_values();
+        let std_dev = std_dev(&returns).filter(|&std_dev| std_dev > 0.)?;
+        let excess = mean(&returns)? - risk_free_rate / TRADING_DAYS_PER_YEAR;
+        Some(excess / std_dev * TRADING_DAYS_PER_YEAR.sqrt())
+    }
+
+    /// The return between each point and the previous point with equity and profit or loss.
+    fn point_returns(&self) -> impl Iterator<Item = (DateTime<Utc>, f64)> + '_ {
+        let mut previous: Option<(f64, f64)> = None;
+        self.rows().filter_map(move |row| {
+            let equity = row.equity?.to_f64()?;
+            let profit_loss = row.profit_loss?.to_f64()?;
+            let (last_equity, last_profit_loss) = previous.replace((equity, profit_loss))?;
+            let point_return = (profit_loss - last_profit_loss) / last_equity;
+            (last_equity > 0.).then_some((row.timestamp, point_return))
+        })
+    }
+
+    fn return_values(&self) -> Vec<f64> {
+        self.daily_returns()
+            .into_iter()
+            .map(|(_, daily_return)| daily_return)
+            .collect()
+    }
+}
+
+fn mean(values: &[f64]) -> Option<f64> {
+    if values.is_empty() {
+        return None;
+    }
+    Some(values.iter().sum::<f64>() / values.len() as f64)
+}
+
+/// The sample standard deviation of a set of values.
+fn std_dev(values: &[f64]) -> Option<f64> {
+    if values.len() < 2 {
+        return None;
+    }
+    let mean = mean(values)?;
+    let variance = values
+        .iter()
+        .map(|value| (value - mean).powi(2))
+        .sum::<f64>()
+        / (values.len() - 1) as f64;
+    Some(variance.sqrt())
+}
+
+fn unix_timestamps<'de, D>(deserializer: D) -> Result<Vec<DateTime<Utc>>, D::Error>
+    where
+        D: Deserializer<'de>,
+{
+    Option::<Vec<i64>>::deserialize(deserializer)?
+        .unwrap_or_default()
+        .into_iter()
+        .map(|timestamp| {
+            DateTime::from_timestamp(timestamp, 0)
+                .ok_or_else(|| D::Error::custom(format!("invalid timestamp `{}`", timestamp)))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+    use super::*;
+
+    const EPSILON: f64 = 1e-9;
+
+    /// A daily history starting on 2024-01-02 with the given equity and profit or loss.
+    fn history(points: &[(i64, i64)]) -> PortfolioHistory {
+        // 2024-01-02 00:00 at the exchange.
+        let start = 1704171600;
+        let timestamps: Vec<_> = (0..points.len() as i64).map(|day| start + day * 86400).collect();
+        serde_json::from_value(json!({
+            "timestamp": timestamps,
+            "equity": points.iter().map(|(equity, _)| equity.to_string()).collect::<Vec<_>>(),
+            "profit_loss": points.iter().map(|(_, pl)| pl.to_string()).collect::<Vec<_>>(),
+            "profit_loss_pct": points.iter().map(|_| "0").collect::<Vec<_>>(),
+            "base_value": "1000",
+            "timeframe": "1D",
+        }))
+        .unwrap()
+    }
+
+    fn assert_close(actual: f64, expected: f64) {
+        assert!((actual - expected).abs() < EPSILON, "{} != {}", actual, expected);
+    }
+
+    #[test]
+    fn no_points() {
+        let history = history(&[]);
+
+        assert!(history.daily_returns().is_empty());
+        assert_eq!(history.max_drawdown(), None);
+        assert_eq!(history.volatility(), None);
+        assert_eq!(history.sharpe_ratio(0.), None);
+    }
+
+    #[test]
+    fn one_point() {
+        let history = history(&[(1000, 0)]);
+
+        assert!(history.daily_returns().is_empty());
+        assert_eq!(history.max_drawdown(), None);
+        assert_eq!(history.volatility(), None);
+        assert_eq!(history.sharpe_ratio(0.), None);
+    }
+
+    #[test]
+    fn flat() {
+        let history = history(&[(1000, 0), (1000, 0), (1000, 0)]);
+
+        let returns: Vec<_> = history.daily_returns().into_iter().map(|(_, r)| r).collect();
+        assert_eq!(returns, [0., 0.]);
+        assert_eq!(history.max_drawdown(), Some(0.));
+        assert_eq!(history.volatility(), Some(0.));
+        assert_eq!(history.sharpe_ratio(0.), None);
+    }
+
+    #[test]
+    fn monotonic() {
+        let history = history(&[(1000, 0), (1100, 100), (1210, 210), (1452, 452)]);
+
+        let returns = history.daily_returns();
+        assert_eq!(
+            returns.iter().map(|(date, _)| date.to_string()).collect::<Vec<_>>(),
+            ["2024-01-03", "2024-01-04", "2024-01-05"],
+        );
+        assert_close(returns[0].1, 0.1);
+        assert_close(returns[1].1, 0.1);
+        assert_close(returns[2].1, 0.2);
+        assert_eq!(history.max_drawdown(), Some(0.));
+        assert!(history.volatility().unwrap() > 0.);
+        assert!(history.sharpe_ratio(0.).unwrap() > 0.);
+    }
+
+    #[test]
+    fn single_drawdown() {
+        let history = history(&[(1000, 0), (1200, 200), (900, -100), (1000, 0)]);
+
+        assert_close(history.max_drawdown().unwrap(), 0.25);
+    }
+
+    #[test]
+    fn deposits_and_withdrawals_are_not_returns() {
+        // A deposit of 1000 and later a withdrawal of 1500, with no gains or losses.
+        let history = history(&[(1000, 0), (2000, 0), (2000, 0), (500, 0)]);
+
+        for (_, daily_return) in history.daily_returns() {
+            assert_eq!(daily_return, 0.);
+        }
+        assert_eq!(history.max_drawdown(), Some(0.));
+        assert_eq!(history.sharpe_ratio(0.), None);
+    }
+
+    #[test]
+    fn returns_are_relative_to_equity_after_cash_flows() {
+        // A gain of 100 after a deposit doubled the equity.
+        let history = history(&[(1000, 0), (2000, 0), (2100, 100)]);
+
+        let returns = history.daily_returns();
+        assert_close(returns[1].1, 0.05);
+    }
+
+    #[test]
+    fn intraday_returns_compound_per_day() {
+        let history: PortfolioHistory = serde_json::from_value(json!({
+            // 2024-01-02 10:00, 12:00 and 15:00 and 2024-01-03 10:00 at the exchange.
+            "timestamp": [1704207600, 1704214800, 1704225600, 1704294000],
+            "equity": ["1000", "1100", null, "990"],
+            "profit_loss": ["0", "100", "50", "-10"],
+            "profit_loss_pct": null,
+            "base_value": "1000",
+            "timeframe": "1H",
+        }))
+        .unwrap();
+
+        let returns = history.daily_returns();
+        assert_eq!(returns.len(), 2);
+        assert_close(returns[0].1, 0.1);
+        assert_close(returns[1].1, -0.1);
+        assert_close(history.max_drawdown().unwrap(), 0.1);
+    }
+}
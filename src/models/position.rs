@@ -0,0 +1,54 @@
+use rust_decimal::Decimal;
+use serde::Deserialize;
+use crate::models::{AssetClass, Exchange};
+
+string_enum! {
+    /// The side of a position.
+    pub enum PositionSide {
+        /// A long position.
+        Long => "long",
+        /// A short position.
+        Short => "short",
+    }
+}
+
+/// An open position.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Position {
+    /// The ID of the asset.
+    pub asset_id: String,
+    /// The symbol of the asset.
+    pub symbol: String,
+    /// The exchange of the asset.
+    pub exchange: Exchange,
+    /// The class of the asset.
+    pub asset_class: AssetClass,
+    /// The average entry price.
+    pub avg_entry_price: Decimal,
+    /// The number of shares.
+    pub qty: Decimal,
+    /// The number of shares not reserved by open orders.
+    pub qty_available: Option<Decimal>,
+    /// The side of the position.
+    pub side: PositionSide,
+    /// The total market value.
+    pub market_value: Option<Decimal>,
+    /// The total cost basis.
+    pub cost_basis: Decimal,
+    /// The unrealized profit or loss.
+    pub unrealized_pl: Option<Decimal>,
+    /// The unrealized profit or loss as a fraction of the cost basis.
+    pub unrealized_plpc: Option<Decimal>,
+    /// The unrealized profit or loss for the day.
+    pub unrealized_intraday_pl: Option<Decimal>,
+    /// The unrealized profit or loss for the day as a fraction.
+    pub unrealized_intraday_plpc: Option<Decimal>,
+    /// The current price of the asset.
+    pub current_price: Option<Decimal>,
+    /// The closing price of the asset on the previous trading day.
+    pub lastday_price: Option<Decimal>,
+    /// The change in price since the previous trading day as a fraction.
+    pub change_today: Option<Decimal>,
+    /// Whether the asset is marginable.
+    pub asset_marginable: Option<bool>,
+}
@@ -0,0 +1,20 @@
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use crate::models::Asset;
+
+/// A watchlist of assets.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Watchlist {
+    /// The ID of the watchlist.
+    pub id: String,
+    /// The ID of the account owning the watchlist.
+    pub account_id: String,
+    /// The name of the watchlist.
+    pub name: String,
+    /// When the watchlist was created.
+    pub created_at: DateTime<Utc>,
+    /// When the watchlist was last updated.
+    pub updated_at: DateTime<Utc>,
+    /// The assets of the watchlist; not included when listing watchlists.
+    pub assets: Option<Vec<Asset>>,
+}
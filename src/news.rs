@@ -0,0 +1,155 @@
+use std::borrow::Cow;
+use chrono::{DateTime, Utc};
+use derive_builder::Builder;
+use http::Method;
+use crate::common::{CommaSeparatedList, SortOrder};
+use crate::endpoint::{Endpoint, UrlBase};
+use crate::paged::Pageable;
+use crate::params::QueryParams;
+
+/// The maximum number of articles Alpaca returns per page.
+const MAX_LIMIT: u64 = 50;
+
+/// Query for news articles.
+///
+/// Returns `models::News`; when paged, each item is a `models::NewsArticle`.
+#[derive(Debug, Clone, Builder)]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
+pub struct News {
+    /// Only return articles about these symbols.
+    #[builder(setter(name = "_symbols"), default, private)]
+    symbols: Option<CommaSeparatedList<String>>,
+    /// The inclusive start of the interval.
+    #[builder(default)]
+    start: Option<DateTime<Utc>>,
+    /// The inclusive end of the interval.
+    #[builder(default)]
+    end: Option<DateTime<Utc>>,
+    /// The order of the articles by publication time.
+    #[builder(default)]
+    sort: Option<SortOrder>,
+    /// Include the content of the articles.
+    #[builder(default)]
+    include_content: Option<bool>,
+    /// Leave out articles without content.
+    #[builder(default)]
+    exclude_contentless: Option<bool>,
+    /// The maximum number of articles per page.
+    #[builder(default)]
+    limit: Option<u64>,
+    /// The page to start from.
+    #[builder(setter(into), default)]
+    page_token: Option<String>,
+}
+
+impl News {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> NewsBuilder {
+        NewsBuilder::default()
+    }
+}
+
+symbol_setters!(NewsBuilder, optional);
+
+impl NewsBuilder {
+    fn validate(&self) -> Result<(), String> {
+        if let (Some(Some(start)), Some(Some(end))) = (self.start, self.end) {
+            if start > end {
+                return Err("`start` must not be after `end`".into());
+            }
+        }
+        if let Some(Some(limit)) = self.limit {
+            if !(1..=MAX_LIMIT).contains(&limit) {
+                return Err(format!("`limit` must be between 1 and {}", MAX_LIMIT));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Endpoint for News {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "news".into()
+    }
+
+    fn url_base(&self) -> UrlBase {
+        UrlBase::DataV1Beta1
+    }
+
+    fn parameters(&self) -> QueryParams<'_> {
+        let mut params = QueryParams::default();
+
+        params
+            .push_opt("symbols", self.symbols.as_ref())
+            .push_opt("start", self.start)
+            .push_opt("end", self.end)
+            .push_opt("sort", self.sort)
+            .push_opt("include_content", self.include_content)
+            .push_opt("exclude_contentless", self.exclude_contentless)
+            .push_opt("limit", self.limit)
+            .push_opt("page_token", self.page_token.as_ref());
+
+        params
+    }
+}
+
+impl Pageable for News {
+    fn items_field(&self) -> &'static str {
+        "news"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn news_defaults() {
+        let endpoint = News::builder().build().unwrap();
+
+        assert_eq!(endpoint.endpoint(), "news");
+        assert_eq!(endpoint.url_base(), UrlBase::DataV1Beta1);
+        assert_eq!(endpoint.parameters().to_query_string(), "");
+    }
+
+    #[test]
+    fn news_params() {
+        let endpoint = News::builder()
+            .symbol("AAPL")
+            .symbols(["TSLA", "BTCUSD"].into_iter())
+            .start("2024-01-01T00:00:00Z".parse().unwrap())
+            .sort(SortOrder::Ascending)
+            .include_content(true)
+            .exclude_contentless(true)
+            .limit(50)
+            .page_token("abc")
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            endpoint.parameters().to_query_string(),
+            "symbols=AAPL%2CTSLA%2CBTCUSD&start=2024-01-01T00%3A00%3A00Z&sort=asc\
+             &include_content=true&exclude_contentless=true&limit=50&page_token=abc",
+        );
+    }
+
+    #[test]
+    fn news_limits() {
+        for limit in [0, 51] {
+            let err = News::builder().limit(limit).build().unwrap_err();
+            assert_eq!(err.to_string(), "`limit` must be between 1 and 50");
+        }
+
+        let err = News::builder()
+            .start("2024-01-02T00:00:00Z".parse().unwrap())
+            .end("2024-01-01T00:00:00Z".parse().unwrap())
+            .build()
+            .unwrap_err();
+        assert_eq!(err.to_string(), "`start` must not be after `end`");
+    }
+}
@@ -0,0 +1,381 @@
+use std::borrow::Cow;
+use std::fmt;
+use std::str::FromStr;
+use chrono::{Datelike, NaiveDate};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use thiserror::Error;
+use crate::models::OptionType;
+use crate::params::ParamValue;
+
+/// The length of the date, type and strike suffix of an OCC symbol.
+const SUFFIX_LEN: usize = 15;
+/// The maximum length of the root of an OCC symbol.
+const MAX_ROOT_LEN: usize = 6;
+/// The number of digits encoding the strike price.
+const STRIKE_DIGITS: usize = 8;
+/// The number of decimal places of the strike price.
+const STRIKE_SCALE: u32 = 3;
+
+/// Errors which may occur when parsing or building an OCC option symbol.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[non_exhaustive]
+pub enum OptionSymbolError {
+    /// The symbol is too short or too long.
+    #[error("option symbol `{}` has an invalid length", symbol)]
+    Length { symbol: String },
+    /// The root is empty, too long or not alphanumeric.
+    #[error("invalid option root `{}`", root)]
+    Root { root: String },
+    /// The expiration date is not a valid `YYMMDD` date in 2000-2099.
+    #[error("invalid option expiration date `{}`", date)]
+    ExpirationDate { date: String },
+    /// The contract type is neither `C` nor `P`.
+    #[error("invalid option type `{}`", option_type)]
+    OptionType { option_type: String },
+    /// The strike price is negative, too large or has more than three decimal places.
+    #[error("invalid option strike price `{}`", strike)]
+    Strike { strike: String },
+}
+
+/// An OCC option symbol such as `AAPL240621C00190000`.
+///
+/// The symbol is made of the root, the expiration date as `YYMMDD`, `C` or `P` and the strike
+/// price times 1000 padded to eight digits. It converts into a `String`, so it may be given to any
+/// builder taking a symbol.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct OptionSymbol {
+    root: String,
+    expiration: NaiveDate,
+    option_type: OptionType,
+    strike: Decimal,
+}
+
+impl OptionSymbol {
+    /// Create a symbol from its parts.
+    pub fn new<R>(
+        root: R,
+        expiration: NaiveDate,
+        option_type: OptionType,
+        strike: Decimal,
+    ) -> Result<Self, OptionSymbolError>
+        where
+            R: Into<String>,
+    {
+        let root = root.into();
+        if root.is_empty()
+            || root.len() > MAX_ROOT_LEN
+            || !root.chars().all(|c| c.is_ascii_uppercase() || c.is_ascii_digit())
+        {
+            return Err(OptionSymbolError::Root { root });
+        }
+        if !(2000..=2099).contains(&expiration.year()) {
+            return Err(OptionSymbolError::ExpirationDate {
+                date: expiration.to_string(),
+            });
+        }
+        if let OptionType::Unknown(option_type) = option_type {
+            return Err(OptionSymbolError::OptionType { option_type });
+        }
+        let max_strike = Decimal::from(10u64.pow((STRIKE_DIGITS as u32) - STRIKE_SCALE));
+        if strike.is_sign_negative() || strike >= max_strike || strike.normalize().scale() > STRIKE_SCALE
+        {
+            return Err(OptionSymbolError::Strike {
+                strike: strike.to_string(),
+            });
+        }
+
+        Ok(OptionSymbol {
+            root,
+            expiration,
+            option_type,
+            strike: strike.normalize(),
+        })
+    }
+
+    /// The root symbol, usually the underlying symbol.
+    pub fn root(&self) -> &str {
+        &self.root
+    }
+
+    /// The expiration date.
+    pub fn expiration(&self) -> NaiveDate {
+        self.expiration
+    }
+
+    /// Whether the contract is a call or a put.
+    pub fn option_type(&self) -> &OptionType {
+        &self.option_type
+    }
+
+    /// The strike price.
+    pub fn strike(&self) -> Decimal {
+        self.strike
+    }
+}
+
+impl FromStr for OptionSymbol {
+    type Err = OptionSymbolError;
+
+    /// Parse a symbol, with or without the padding of the root to six characters.
+    fn from_str(symbol: &str) -> Result<Self, Self::Err> {
+        let length_error = || OptionSymbolError::Length {
+            symbol: symbol.into(),
+        };
+        if !symbol.is_ascii() || symbol.len() <= SUFFIX_LEN {
+            return Err(length_error());
+        }
+
+        let (root, suffix) = symbol.split_at(symbol.len() - SUFFIX_LEN);
+        let root = root.trim_end();
+        if root.len() > MAX_ROOT_LEN {
+            return Err(length_error());
+        }
+        let (date, rest) = suffix.split_at(6);
+        let (option_type, strike) = rest.split_at(1);
+
+        let expiration = parse_date(date).ok_or_else(|| {
+            OptionSymbolError::ExpirationDate {
+                date: date.into(),
+            }
+        })?;
+        let option_type = match option_type {
+            "C" => OptionType::Call,
+            "P" => OptionType::Put,
+            _ => {
+                return Err(OptionSymbolError::OptionType {
+                    option_type: option_type.into(),
+                })
+            },
+        };
+        let strike = if strike.bytes().all(|b| b.is_ascii_digit()) {
+            strike.parse::<i64>().ok()
+        } else {
+            None
+        }
+        .map(|strike| Decimal::new(strike, STRIKE_SCALE))
+        .ok_or_else(|| {
+            OptionSymbolError::Strike {
+                strike: strike.into(),
+            }
+        })?;
+
+        OptionSymbol::new(root, expiration, option_type, strike)
+    }
+}
+
+/// Parse a `YYMMDD` date in 2000-2099.
+fn parse_date(date: &str) -> Option<NaiveDate> {
+    if !date.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    let year = date[0..2].parse::<i32>().ok()?;
+    let month = date[2..4].parse().ok()?;
+    let day = date[4..6].parse().ok()?;
+    NaiveDate::from_ymd_opt(2000 + year, month, day)
+}
+
+impl fmt::Display for OptionSymbol {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let option_type = match self.option_type {
+            OptionType::Call => 'C',
+            OptionType::Put => 'P',
+            OptionType::Unknown(_) => unreachable!("validated on construction"),
+        };
+        let mut strike = self.strike;
+        strike.rescale(STRIKE_SCALE);
+        write!(
+            f,
+            "{}{}{}{:0width$}",
+            self.root,
+            self.expiration.format("%y%m%d"),
+            option_type,
+            strike.mantissa(),
+            width = STRIKE_DIGITS,
+        )
+    }
+}
+
+impl From<OptionSymbol> for String {
+    fn from(symbol: OptionSymbol) -> Self {
+        symbol.to_string()
+    }
+}
+
+impl From<&OptionSymbol> for String {
+    fn from(symbol: &OptionSymbol) -> Self {
+        symbol.to_string()
+    }
+}
+
+impl Serialize for OptionSymbol {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for OptionSymbol {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+    {
+        let symbol = String::deserialize(deserializer)?;
+        symbol.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+impl ParamValue<'static> for OptionSymbol {
+    fn as_value(&self) -> Cow<'static, str> {
+        self.to_string().into()
+    }
+}
+
+impl ParamValue<'static> for &OptionSymbol {
+    fn as_value(&self) -> Cow<'static, str> {
+        (*self).as_value()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn round_trips() {
+        let symbol: OptionSymbol = "AAPL240119C00190000".parse().unwrap();
+
+        assert_eq!(symbol.root(), "AAPL");
+        assert_eq!(symbol.expiration(), date(2024, 1, 19));
+        assert_eq!(*symbol.option_type(), OptionType::Call);
+        assert_eq!(symbol.strike(), Decimal::new(190, 0));
+        assert_eq!(symbol.to_string(), "AAPL240119C00190000");
+    }
+
+    #[test]
+    fn fractional_strikes() {
+        let symbol: OptionSymbol = "SPY240119P00472500".parse().unwrap();
+        assert_eq!(*symbol.option_type(), OptionType::Put);
+        assert_eq!(symbol.strike(), Decimal::new(4725, 1));
+        assert_eq!(symbol.to_string(), "SPY240119P00472500");
+
+        let symbol: OptionSymbol = "F240119C00000125".parse().unwrap();
+        assert_eq!(symbol.strike(), Decimal::new(125, 3));
+        assert_eq!(symbol.to_string(), "F240119C00000125");
+    }
+
+    #[test]
+    fn six_character_roots() {
+        let symbol: OptionSymbol = "GOOGL1240119C00140000".parse().unwrap();
+
+        assert_eq!(symbol.root(), "GOOGL1");
+        assert_eq!(symbol.to_string(), "GOOGL1240119C00140000");
+    }
+
+    #[test]
+    fn padded_roots() {
+        let symbol: OptionSymbol = "AAPL  240119C00190000".parse().unwrap();
+
+        assert_eq!(symbol.root(), "AAPL");
+        assert_eq!(symbol.to_string(), "AAPL240119C00190000");
+    }
+
+    #[test]
+    fn builds_from_parts() {
+        let symbol =
+            OptionSymbol::new("AAPL", date(2024, 6, 21), OptionType::Put, Decimal::new(1825, 1))
+                .unwrap();
+
+        assert_eq!(symbol.to_string(), "AAPL240621P00182500");
+        assert_eq!(String::from(&symbol), "AAPL240621P00182500");
+    }
+
+    #[test]
+    fn rejects_bad_lengths() {
+        for symbol in ["", "AAPL", "240119C00190000", "TOOLONG240119C00190000"] {
+            assert!(
+                matches!(symbol.parse::<OptionSymbol>(), Err(OptionSymbolError::Length { .. })),
+                "{}",
+                symbol,
+            );
+        }
+    }
+
+    #[test]
+    fn rejects_bad_dates() {
+        for symbol in ["AAPL241319C00190000", "AAPL240230C00190000", "AAPL24O119C00190000"] {
+            assert!(
+                matches!(
+                    symbol.parse::<OptionSymbol>(),
+                    Err(OptionSymbolError::ExpirationDate { .. }),
+                ),
+                "{}",
+                symbol,
+            );
+        }
+    }
+
+    #[test]
+    fn rejects_bad_types() {
+        let err = "AAPL240119X00190000".parse::<OptionSymbol>().unwrap_err();
+
+        assert_eq!(
+            err,
+            OptionSymbolError::OptionType {
+                option_type: "X".into(),
+            },
+        );
+    }
+
+    #[test]
+    fn rejects_non_digit_strikes() {
+        for symbol in ["AAPL240119C0019000A", "AAPL240119C-0190000", "AAPL240119C 0190000"] {
+            assert!(
+                matches!(symbol.parse::<OptionSymbol>(), Err(OptionSymbolError::Strike { .. })),
+                "{}",
+                symbol,
+            );
+        }
+    }
+
+    #[test]
+    fn rejects_bad_roots() {
+        for symbol in ["aapl240119C00190000", "AA-L240119C00190000"] {
+            assert!(
+                matches!(symbol.parse::<OptionSymbol>(), Err(OptionSymbolError::Root { .. })),
+                "{}",
+                symbol,
+            );
+        }
+    }
+
+    #[test]
+    fn rejects_bad_parts() {
+        let expiration = date(2024, 1, 19);
+
+        assert!(OptionSymbol::new("AAPL", expiration, OptionType::Call, Decimal::NEGATIVE_ONE)
+            .is_err());
+        assert!(OptionSymbol::new("AAPL", expiration, OptionType::Call, Decimal::new(1, 4))
+            .is_err());
+        assert!(OptionSymbol::new("AAPL", expiration, OptionType::Call, Decimal::new(100000, 0))
+            .is_err());
+        assert!(OptionSymbol::new("AAPL", date(2100, 1, 1), OptionType::Call, Decimal::ONE)
+            .is_err());
+        assert!(OptionSymbol::new("AAPL", expiration, "x".into(), Decimal::ONE).is_err());
+        assert!(OptionSymbol::new("", expiration, OptionType::Call, Decimal::ONE).is_err());
+    }
+
+    #[test]
+    fn serializes_as_string() {
+        let symbol: OptionSymbol = serde_json::from_str("\"AAPL240119C00190000\"").unwrap();
+
+        assert_eq!(serde_json::to_string(&symbol).unwrap(), "\"AAPL240119C00190000\"");
+        assert!(serde_json::from_str::<OptionSymbol>("\"AAPL\"").is_err());
+    }
+}
@@ -0,0 +1,560 @@
+use std::borrow::Cow;
+use chrono::{DateTime, NaiveDate, Utc};
+use derive_builder::Builder;
+use http::Method;
+use rust_decimal::Decimal;
+use crate::common::{CommaSeparatedList, SortOrder};
+use crate::endpoint::{Endpoint, UrlBase};
+use crate::market_data::{validate_bars, validate_limit, TimeFrame};
+use crate::models::OptionType;
+use crate::paged::Pageable;
+use crate::params::{ParamValue, QueryParams};
+
+/// The maximum number of snapshots Alpaca returns per page.
+const MAX_SNAPSHOT_LIMIT: u64 = 1000;
+
+/// The source of option market data.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum OptionFeed {
+    /// The Options Price Reporting Authority feed (requires a subscription).
+    Opra,
+    /// Alpaca's indicative feed, derived from OPRA.
+    Indicative,
+}
+
+impl OptionFeed {
+    /// The feed as a query parameter.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            OptionFeed::Opra => "opra",
+            OptionFeed::Indicative => "indicative",
+        }
+    }
+}
+
+impl ParamValue<'static> for OptionFeed {
+    fn as_value(&self) -> Cow<'static, str> {
+        self.as_str().into()
+    }
+}
+
+/// Query for historical bars of option contracts.
+///
+/// Returns `models::Bars`; when paged, each item is a `(symbol, models::Bar)` pair.
+#[derive(Debug, Clone, Builder)]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
+pub struct OptionBars {
+    /// The contract symbols to query.
+    #[builder(setter(name = "_symbols"), private)]
+    symbols: CommaSeparatedList<String>,
+    /// The time frame of each bar.
+    timeframe: TimeFrame,
+    /// The inclusive start of the interval.
+    #[builder(default)]
+    start: Option<DateTime<Utc>>,
+    /// The inclusive end of the interval.
+    #[builder(default)]
+    end: Option<DateTime<Utc>>,
+    /// The maximum number of bars per page across all symbols.
+    #[builder(default)]
+    limit: Option<u64>,
+    /// The page to start from.
+    #[builder(setter(into), default)]
+    page_token: Option<String>,
+    /// The order of the bars by timestamp.
+    #[builder(default)]
+    sort: Option<SortOrder>,
+}
+
+impl OptionBars {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> OptionBarsBuilder {
+        OptionBarsBuilder::default()
+    }
+}
+
+symbol_setters!(OptionBarsBuilder);
+
+impl OptionBarsBuilder {
+    fn validate(&self) -> Result<(), String> {
+        self.validate_symbols()?;
+
+        validate_bars(self.timeframe, self.limit.flatten())
+    }
+}
+
+impl Endpoint for OptionBars {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "options/bars".into()
+    }
+
+    fn url_base(&self) -> UrlBase {
+        UrlBase::DataV1Beta1
+    }
+
+    fn parameters(&self) -> QueryParams<'_> {
+        let mut params = QueryParams::default();
+
+        params
+            .push("symbols", &self.symbols)
+            .push("timeframe", self.timeframe)
+            .push_opt("start", self.start)
+            .push_opt("end", self.end)
+            .push_opt("limit", self.limit)
+            .push_opt("page_token", self.page_token.as_ref())
+            .push_opt("sort", self.sort);
+
+        params
+    }
+}
+
+impl Pageable for OptionBars {
+    fn items_field(&self) -> &'static str {
+        "bars"
+    }
+}
+
+/// Query for historical trades of option contracts.
+///
+/// Returns `models::Trades`; when paged, each item is a `(symbol, models::Trade)` pair.
+#[derive(Debug, Clone, Builder)]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
+pub struct OptionTrades {
+    /// The contract symbols to query.
+    #[builder(setter(name = "_symbols"), private)]
+    symbols: CommaSeparatedList<String>,
+    /// The inclusive start of the interval.
+    #[builder(default)]
+    start: Option<DateTime<Utc>>,
+    /// The inclusive end of the interval.
+    #[builder(default)]
+    end: Option<DateTime<Utc>>,
+    /// The maximum number of trades per page across all symbols.
+    #[builder(default)]
+    limit: Option<u64>,
+    /// The page to start from.
+    #[builder(setter(into), default)]
+    page_token: Option<String>,
+    /// The order of the trades by timestamp.
+    #[builder(default)]
+    sort: Option<SortOrder>,
+}
+
+impl OptionTrades {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> OptionTradesBuilder {
+        OptionTradesBuilder::default()
+    }
+}
+
+symbol_setters!(OptionTradesBuilder);
+
+impl OptionTradesBuilder {
+    fn validate(&self) -> Result<(), String> {
+        self.validate_symbols()?;
+
+        validate_limit(self.limit.flatten())
+    }
+}
+
+impl Endpoint for OptionTrades {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "options/trades".into()
+    }
+
+    fn url_base(&self) -> UrlBase {
+        UrlBase::DataV1Beta1
+    }
+
+    fn parameters(&self) -> QueryParams<'_> {
+        let mut params = QueryParams::default();
+
+        params
+            .push("symbols", &self.symbols)
+            .push_opt("start", self.start)
+            .push_opt("end", self.end)
+            .push_opt("limit", self.limit)
+            .push_opt("page_token", self.page_token.as_ref())
+            .push_opt("sort", self.sort);
+
+        params
+    }
+}
+
+impl Pageable for OptionTrades {
+    fn items_field(&self) -> &'static str {
+        "trades"
+    }
+}
+
+/// Query for the latest quote of option contracts.
+///
+/// Returns `models::LatestQuotes`.
+#[derive(Debug, Clone, Builder)]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate_symbols"))]
+pub struct LatestOptionQuotes {
+    /// The contract symbols to query.
+    #[builder(setter(name = "_symbols"), private)]
+    symbols: CommaSeparatedList<String>,
+    /// The source of the data.
+    #[builder(default)]
+    feed: Option<OptionFeed>,
+}
+
+impl LatestOptionQuotes {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> LatestOptionQuotesBuilder {
+        LatestOptionQuotesBuilder::default()
+    }
+}
+
+symbol_setters!(LatestOptionQuotesBuilder);
+
+impl Endpoint for LatestOptionQuotes {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "options/quotes/latest".into()
+    }
+
+    fn url_base(&self) -> UrlBase {
+        UrlBase::DataV1Beta1
+    }
+
+    fn parameters(&self) -> QueryParams<'_> {
+        let mut params = QueryParams::default();
+
+        params
+            .push("symbols", &self.symbols)
+            .push_opt("feed", self.feed);
+
+        params
+    }
+}
+
+/// Query for the latest trade of option contracts.
+///
+/// Returns `models::LatestTrades`.
+#[derive(Debug, Clone, Builder)]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate_symbols"))]
+pub struct LatestOptionTrades {
+    /// The contract symbols to query.
+    #[builder(setter(name = "_symbols"), private)]
+    symbols: CommaSeparatedList<String>,
+    /// The source of the data.
+    #[builder(default)]
+    feed: Option<OptionFeed>,
+}
+
+impl LatestOptionTrades {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> LatestOptionTradesBuilder {
+        LatestOptionTradesBuilder::default()
+    }
+}
+
+symbol_setters!(LatestOptionTradesBuilder);
+
+impl Endpoint for LatestOptionTrades {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "options/trades/latest".into()
+    }
+
+    fn url_base(&self) -> UrlBase {
+        UrlBase::DataV1Beta1
+    }
+
+    fn parameters(&self) -> QueryParams<'_> {
+        let mut params = QueryParams::default();
+
+        params
+            .push("symbols", &self.symbols)
+            .push_opt("feed", self.feed);
+
+        params
+    }
+}
+
+/// Query for snapshots of option contracts.
+///
+/// Returns `models::OptionSnapshots`; when paged, each item is a `(symbol, models::OptionSnapshot)`
+/// pair.
+#[derive(Debug, Clone, Builder)]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
+pub struct OptionSnapshots {
+    /// The contract symbols to query.
+    #[builder(setter(name = "_symbols"), private)]
+    symbols: CommaSeparatedList<String>,
+    /// The source of the data.
+    #[builder(default)]
+    feed: Option<OptionFeed>,
+    /// Only return contracts updated since this time.
+    #[builder(default)]
+    updated_since: Option<DateTime<Utc>>,
+    /// The maximum number of snapshots per page across all symbols.
+    #[builder(default)]
+    limit: Option<u64>,
+    /// The page to start from.
+    #[builder(setter(into), default)]
+    page_token: Option<String>,
+}
+
+impl OptionSnapshots {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> OptionSnapshotsBuilder {
+        OptionSnapshotsBuilder::default()
+    }
+}
+
+symbol_setters!(OptionSnapshotsBuilder);
+
+impl OptionSnapshotsBuilder {
+    fn validate(&self) -> Result<(), String> {
+        self.validate_symbols()?;
+
+        validate_snapshot_limit(self.limit.flatten())
+    }
+}
+
+impl Endpoint for OptionSnapshots {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "options/snapshots".into()
+    }
+
+    fn url_base(&self) -> UrlBase {
+        UrlBase::DataV1Beta1
+    }
+
+    fn parameters(&self) -> QueryParams<'_> {
+        let mut params = QueryParams::default();
+
+        params
+            .push("symbols", &self.symbols)
+            .push_opt("feed", self.feed)
+            .push_opt("updated_since", self.updated_since)
+            .push_opt("limit", self.limit)
+            .push_opt("page_token", self.page_token.as_ref());
+
+        params
+    }
+}
+
+impl Pageable for OptionSnapshots {
+    fn items_field(&self) -> &'static str {
+        "snapshots"
+    }
+}
+
+/// Query for the option chain of an underlying symbol.
+///
+/// Returns `models::OptionSnapshots`; when paged, each item is a `(symbol, models::OptionSnapshot)`
+/// pair.
+#[derive(Debug, Clone, Builder)]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
+pub struct OptionChain {
+    /// The symbol of the underlying asset.
+    #[builder(setter(into))]
+    underlying_symbol: String,
+    /// The source of the data.
+    #[builder(default)]
+    feed: Option<OptionFeed>,
+    /// Only return contracts of this type.
+    #[builder(default)]
+    option_type: Option<OptionType>,
+    /// Only return contracts with a strike price at or above this price.
+    #[builder(default)]
+    strike_price_gte: Option<Decimal>,
+    /// Only return contracts with a strike price at or below this price.
+    #[builder(default)]
+    strike_price_lte: Option<Decimal>,
+    /// Only return contracts expiring on this date.
+    #[builder(default)]
+    expiration_date: Option<NaiveDate>,
+    /// Only return contracts expiring on or after this date.
+    #[builder(default)]
+    expiration_date_gte: Option<NaiveDate>,
+    /// Only return contracts expiring on or before this date.
+    #[builder(default)]
+    expiration_date_lte: Option<NaiveDate>,
+    /// Only return contracts with this root symbol.
+    #[builder(setter(into), default)]
+    root_symbol: Option<String>,
+    /// Only return contracts updated since this time.
+    #[builder(default)]
+    updated_since: Option<DateTime<Utc>>,
+    /// The maximum number of snapshots per page.
+    #[builder(default)]
+    limit: Option<u64>,
+    /// The page to start from.
+    #[builder(setter(into), default)]
+    page_token: Option<String>,
+}
+
+impl OptionChain {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> OptionChainBuilder {
+        OptionChainBuilder::default()
+    }
+}
+
+impl OptionChainBuilder {
+    fn validate(&self) -> Result<(), String> {
+        if self.underlying_symbol.as_deref().is_some_and(str::is_empty) {
+            return Err("`underlying_symbol` must not be empty".into());
+        }
+        if let (Some(Some(gte)), Some(Some(lte))) = (self.strike_price_gte, self.strike_price_lte) {
+            if gte > lte {
+                return Err("`strike_price_gte` must not be above `strike_price_lte`".into());
+            }
+        }
+        if let (Some(Some(gte)), Some(Some(lte))) =
+            (self.expiration_date_gte, self.expiration_date_lte)
+        {
+            if gte > lte {
+                return Err("`expiration_date_gte` must not be after `expiration_date_lte`".into());
+            }
+        }
+
+        validate_snapshot_limit(self.limit.flatten())
+    }
+}
+
+impl Endpoint for OptionChain {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("options/snapshots/{}", self.underlying_symbol).into()
+    }
+
+    fn url_base(&self) -> UrlBase {
+        UrlBase::DataV1Beta1
+    }
+
+    fn parameters(&self) -> QueryParams<'_> {
+        let mut params = QueryParams::default();
+
+        params
+            .push_opt("feed", self.feed)
+            .push_opt("type", self.option_type.as_ref())
+            .push_opt("strike_price_gte", self.strike_price_gte)
+            .push_opt("strike_price_lte", self.strike_price_lte)
+            .push_opt("expiration_date", self.expiration_date)
+            .push_opt("expiration_date_gte", self.expiration_date_gte)
+            .push_opt("expiration_date_lte", self.expiration_date_lte)
+            .push_opt("root_symbol", self.root_symbol.as_ref())
+            .push_opt("updated_since", self.updated_since)
+            .push_opt("limit", self.limit)
+            .push_opt("page_token", self.page_token.as_ref());
+
+        params
+    }
+}
+
+impl Pageable for OptionChain {
+    fn items_field(&self) -> &'static str {
+        "snapshots"
+    }
+}
+
+fn validate_snapshot_limit(limit: Option<u64>) -> Result<(), String> {
+    if let Some(limit) = limit {
+        if !(1..=MAX_SNAPSHOT_LIMIT).contains(&limit) {
+            return Err(format!("`limit` must be between 1 and {}", MAX_SNAPSHOT_LIMIT));
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn option_data_require_a_symbol() {
+        let mut builder = OptionBars::builder();
+        builder.timeframe(TimeFrame::DAY);
+        assert!(builder.build().is_err());
+        assert!(OptionTrades::builder().build().is_err());
+        assert!(LatestOptionQuotes::builder().build().is_err());
+        assert!(OptionSnapshots::builder().build().is_err());
+    }
+
+    #[test]
+    fn option_snapshots_limit_page_size() {
+        let mut builder = OptionSnapshots::builder();
+        builder.symbol("AAPL240119C00190000").limit(MAX_SNAPSHOT_LIMIT + 1);
+        assert!(builder.build().is_err());
+
+        builder.limit(MAX_SNAPSHOT_LIMIT);
+        builder.build().unwrap();
+    }
+
+    #[test]
+    fn option_chain_rejects_an_empty_underlying_symbol() {
+        assert!(OptionChain::builder().underlying_symbol("").build().is_err());
+    }
+
+    #[test]
+    fn option_chain_rejects_inverted_ranges() {
+        let mut builder = OptionChain::builder();
+        builder
+            .underlying_symbol("AAPL")
+            .strike_price_gte(Decimal::new(200, 0))
+            .strike_price_lte(Decimal::new(180, 0));
+        assert!(builder.build().is_err());
+
+        let mut builder = OptionChain::builder();
+        builder
+            .underlying_symbol("AAPL")
+            .expiration_date_gte(NaiveDate::from_ymd_opt(2024, 2, 1).unwrap())
+            .expiration_date_lte(NaiveDate::from_ymd_opt(2024, 1, 1).unwrap());
+        assert!(builder.build().is_err());
+
+        let mut builder = OptionChain::builder();
+        builder.underlying_symbol("AAPL").limit(0);
+        assert!(builder.build().is_err());
+    }
+
+    #[test]
+    fn option_chain_parameters() {
+        let endpoint = OptionChain::builder()
+            .underlying_symbol("AAPL")
+            .feed(OptionFeed::Indicative)
+            .option_type(OptionType::Put)
+            .strike_price_gte(Decimal::new(1800, 1))
+            .strike_price_lte(Decimal::new(190, 0))
+            .expiration_date(NaiveDate::from_ymd_opt(2024, 1, 19).unwrap())
+            .build()
+            .unwrap();
+
+        assert_eq!(endpoint.endpoint(), "options/snapshots/AAPL");
+        assert_eq!(endpoint.url_base(), UrlBase::DataV1Beta1);
+        assert_eq!(
+            endpoint.parameters().to_query_string(),
+            "feed=indicative&type=put&strike_price_gte=180.0&strike_price_lte=190\
+             &expiration_date=2024-01-19",
+        );
+    }
+}
@@ -1,12 +1,64 @@
 use std::borrow::Cow;
+use chrono::NaiveDate;
 use derive_builder::Builder;
 use http::Method;
+use rust_decimal::Decimal;
+use crate::common::CommaSeparatedList;
 use crate::endpoint::Endpoint;
+use crate::models::{OptionStatus, OptionStyle, OptionType};
+use crate::paged::Pageable;
+use crate::params::QueryParams;
 
-/// Query information about the API calling user.
-#[derive(Debug, Clone, Copy, Builder)]
-pub struct OptionsContract {}
+/// The maximum number of contracts Alpaca returns per page.
+const MAX_LIMIT: u64 = 10000;
 
+/// Query for option contracts.
+///
+/// Returns a page of contracts under `option_contracts`; when paged, each item is a
+/// `models::OptionContract`.
+#[derive(Debug, Clone, Builder)]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
+pub struct OptionsContract {
+    /// Only return contracts on these underlying symbols.
+    #[builder(setter(name = "_underlying_symbols"), default, private)]
+    underlying_symbols: Option<CommaSeparatedList<String>>,
+    /// Only return contracts with this status; Alpaca defaults to active contracts.
+    #[builder(default)]
+    status: Option<OptionStatus>,
+    /// Only return contracts expiring on this date.
+    #[builder(default)]
+    expiration_date: Option<NaiveDate>,
+    /// Only return contracts expiring on or after this date.
+    #[builder(default)]
+    expiration_date_gte: Option<NaiveDate>,
+    /// Only return contracts expiring on or before this date.
+    #[builder(default)]
+    expiration_date_lte: Option<NaiveDate>,
+    /// Only return contracts with this root symbol.
+    #[builder(setter(into), default)]
+    root_symbol: Option<String>,
+    /// Only return contracts of this type.
+    #[builder(default)]
+    option_type: Option<OptionType>,
+    /// Only return contracts with this exercise style.
+    #[builder(default)]
+    style: Option<OptionStyle>,
+    /// Only return contracts with a strike price at or above this price.
+    #[builder(default)]
+    strike_price_gte: Option<Decimal>,
+    /// Only return contracts with a strike price at or below this price.
+    #[builder(default)]
+    strike_price_lte: Option<Decimal>,
+    /// The maximum number of contracts per page.
+    #[builder(default)]
+    limit: Option<u64>,
+    /// The page to start from.
+    #[builder(setter(into), default)]
+    page_token: Option<String>,
+    /// Only return contracts with (or without) the penny price increment indicator.
+    #[builder(default)]
+    ppind: Option<bool>,
+}
 
 impl OptionsContract {
     /// Create a builder for the endpoint.
@@ -15,6 +67,38 @@ impl OptionsContract {
     }
 }
 
+list_setters! {
+    impl OptionsContractBuilder {
+        /// Add an underlying symbol to filter on.
+        underlying_symbol,
+        /// Add a set of underlying symbols to filter on.
+        underlying_symbols => underlying_symbols: String, optional;
+    }
+}
+
+impl OptionsContractBuilder {
+    fn validate(&self) -> Result<(), String> {
+        if let (Some(Some(gte)), Some(Some(lte))) = (self.strike_price_gte, self.strike_price_lte) {
+            if gte > lte {
+                return Err("`strike_price_gte` must not be above `strike_price_lte`".into());
+            }
+        }
+        if let (Some(Some(gte)), Some(Some(lte))) =
+            (self.expiration_date_gte, self.expiration_date_lte)
+        {
+            if gte > lte {
+                return Err("`expiration_date_gte` must not be after `expiration_date_lte`".into());
+            }
+        }
+        if let Some(Some(limit)) = self.limit {
+            if !(1..=MAX_LIMIT).contains(&limit) {
+                return Err(format!("`limit` must be between 1 and {}", MAX_LIMIT));
+            }
+        }
+
+        Ok(())
+    }
+}
 
 impl Endpoint for OptionsContract {
     fn method(&self) -> Method {
@@ -24,4 +108,119 @@ impl Endpoint for OptionsContract {
     fn endpoint(&self) -> Cow<'static, str> {
         "options/contracts".into()
     }
+
+    fn parameters(&self) -> QueryParams<'_> {
+        let mut params = QueryParams::default();
+
+        params
+            .push_opt("underlying_symbols", self.underlying_symbols.as_ref())
+            .push_opt("status", self.status.as_ref())
+            .push_opt("expiration_date", self.expiration_date)
+            .push_opt("expiration_date_gte", self.expiration_date_gte)
+            .push_opt("expiration_date_lte", self.expiration_date_lte)
+            .push_opt("root_symbol", self.root_symbol.as_ref())
+            .push_opt("type", self.option_type.as_ref())
+            .push_opt("style", self.style.as_ref())
+            .push_opt("strike_price_gte", self.strike_price_gte)
+            .push_opt("strike_price_lte", self.strike_price_lte)
+            .push_opt("limit", self.limit)
+            .push_opt("page_token", self.page_token.as_ref())
+            .push_opt("ppind", self.ppind);
+
+        params
+    }
+}
+
+impl Pageable for OptionsContract {
+    fn items_field(&self) -> &'static str {
+        "option_contracts"
+    }
+}
+
+/// Query for a single option contract.
+///
+/// Returns a `models::OptionContract`.
+#[derive(Debug, Clone, Builder)]
+pub struct SingleOptionsContract {
+    /// The OCC symbol or ID of the contract.
+    #[builder(setter(into))]
+    symbol_or_id: String,
+}
+
+impl SingleOptionsContract {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> SingleOptionsContractBuilder {
+        SingleOptionsContractBuilder::default()
+    }
+}
+
+impl Endpoint for SingleOptionsContract {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("options/contracts/{}", self.symbol_or_id).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn contracts_reject_inverted_ranges() {
+        let mut builder = OptionsContract::builder();
+        builder
+            .strike_price_gte(Decimal::new(200, 0))
+            .strike_price_lte(Decimal::new(180, 0));
+        assert!(builder.build().is_err());
+
+        let mut builder = OptionsContract::builder();
+        builder
+            .expiration_date_gte(NaiveDate::from_ymd_opt(2024, 2, 1).unwrap())
+            .expiration_date_lte(NaiveDate::from_ymd_opt(2024, 1, 1).unwrap());
+        assert!(builder.build().is_err());
+    }
+
+    #[test]
+    fn contracts_limit_page_size() {
+        let mut builder = OptionsContract::builder();
+        builder.limit(MAX_LIMIT + 1);
+        assert!(builder.build().is_err());
+
+        builder.limit(MAX_LIMIT);
+        builder.build().unwrap();
+    }
+
+    #[test]
+    fn contracts_parameters() {
+        let endpoint = OptionsContract::builder()
+            .underlying_symbol("AAPL")
+            .underlying_symbols(["SPY"].into_iter())
+            .status(OptionStatus::Active)
+            .option_type(OptionType::Call)
+            .style(OptionStyle::American)
+            .expiration_date_lte(NaiveDate::from_ymd_opt(2024, 3, 15).unwrap())
+            .ppind(true)
+            .build()
+            .unwrap();
+
+        assert_eq!(endpoint.items_field(), "option_contracts");
+        assert_eq!(
+            endpoint.parameters().to_query_string(),
+            "underlying_symbols=AAPL%2CSPY&status=active&expiration_date_lte=2024-03-15&type=call\
+             &style=american&ppind=true",
+        );
+    }
+
+    #[test]
+    fn single_contract_endpoint() {
+        let endpoint = SingleOptionsContract::builder()
+            .symbol_or_id("AAPL240119C00190000")
+            .build()
+            .unwrap();
+
+        assert_eq!(endpoint.endpoint(), "options/contracts/AAPL240119C00190000");
+    }
 }
@@ -0,0 +1,782 @@
+use std::borrow::Cow;
+use chrono::{DateTime, Utc};
+use derive_builder::Builder;
+use http::Method;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use crate::common::{is_set, CommaSeparatedList, SortOrder};
+use crate::endpoint::Endpoint;
+use crate::error::BodyError;
+use crate::params::{ParamValue, QueryParams};
+
+pub use crate::models::{OrderClass, OrderSide, OrderType, TimeInForce};
+
+impl OrderType {
+    fn uses_limit_price(&self) -> bool {
+        matches!(self, OrderType::Limit | OrderType::StopLimit)
+    }
+
+    fn uses_stop_price(&self) -> bool {
+        matches!(self, OrderType::Stop | OrderType::StopLimit)
+    }
+}
+
+/// The take-profit leg of an advanced order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct TakeProfit {
+    /// The limit price of the take-profit order.
+    pub limit_price: Decimal,
+}
+
+impl TakeProfit {
+    /// Create a take-profit leg at the given limit price.
+    pub fn new(limit_price: Decimal) -> Self {
+        TakeProfit {
+            limit_price,
+        }
+    }
+}
+
+/// The stop-loss leg of an advanced order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct StopLoss {
+    /// The stop price of the stop-loss order.
+    pub stop_price: Decimal,
+    /// The limit price of the stop-loss order; a stop-limit order is placed if set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit_price: Option<Decimal>,
+}
+
+impl StopLoss {
+    /// Create a stop-loss leg at the given stop price.
+    pub fn new(stop_price: Decimal) -> Self {
+        StopLoss {
+            stop_price,
+            limit_price: None,
+        }
+    }
+
+    /// Use a stop-limit order for the leg.
+    pub fn limit_price(mut self, limit_price: Decimal) -> Self {
+        self.limit_price = Some(limit_price);
+        self
+    }
+}
+
+/// Submit a new order.
+#[derive(Debug, Clone, Builder, Serialize)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct CreateOrder {
+    /// The symbol or asset ID to trade.
+    #[builder(setter(into))]
+    symbol: String,
+    /// The number of shares to trade.
+    #[builder(setter(strip_option), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    qty: Option<Decimal>,
+    /// The dollar amount to trade.
+    #[builder(setter(strip_option), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    notional: Option<Decimal>,
+    /// The side of the order.
+    side: OrderSide,
+    /// The type of the order.
+    #[serde(rename = "type")]
+    order_type: OrderType,
+    /// How long the order remains in effect.
+    time_in_force: TimeInForce,
+    /// The limit price for limit and stop-limit orders.
+    #[builder(setter(strip_option), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    limit_price: Option<Decimal>,
+    /// The stop price for stop and stop-limit orders.
+    #[builder(setter(strip_option), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    stop_price: Option<Decimal>,
+    /// The dollar offset for trailing stop orders.
+    #[builder(setter(strip_option), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    trail_price: Option<Decimal>,
+    /// The percentage offset for trailing stop orders.
+    #[builder(setter(strip_option), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    trail_percent: Option<Decimal>,
+    /// Allow the order to execute in pre- and post-market sessions.
+    #[builder(setter(strip_option), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    extended_hours: Option<bool>,
+    /// A unique identifier for the order chosen by the client.
+    #[builder(setter(into, strip_option), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    client_order_id: Option<String>,
+    /// The class of the order.
+    #[builder(setter(strip_option), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    order_class: Option<OrderClass>,
+    /// The take-profit leg of an advanced order.
+    #[builder(setter(strip_option), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    take_profit: Option<TakeProfit>,
+    /// The stop-loss leg of an advanced order.
+    #[builder(setter(strip_option), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    stop_loss: Option<StopLoss>,
+}
+
+impl CreateOrder {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> CreateOrderBuilder {
+        CreateOrderBuilder::default()
+    }
+}
+
+/// The maximum length of a client order ID accepted by Alpaca.
+const CLIENT_ORDER_ID_MAX_LEN: usize = 128;
+
+impl CreateOrderBuilder {
+    fn validate(&self) -> Result<(), String> {
+        match (is_set(&self.qty), is_set(&self.notional)) {
+            (true, true) => return Err("only one of `qty` or `notional` may be given".into()),
+            (false, false) => return Err("one of `qty` or `notional` is required".into()),
+            _ => {},
+        }
+
+        // Missing required fields are reported by the generated `build` method.
+        if let Some(order_type) = &self.order_type {
+            self.validate_prices(order_type)?;
+
+            if self.extended_hours.flatten() == Some(true)
+                && (*order_type != OrderType::Limit || self.time_in_force != Some(TimeInForce::Day))
+            {
+                return Err(
+                    "`extended_hours` requires a limit order with a `day` time in force".into(),
+                );
+            }
+        }
+
+        if let Some(Some(client_order_id)) = &self.client_order_id {
+            if client_order_id.len() > CLIENT_ORDER_ID_MAX_LEN {
+                return Err(format!(
+                    "`client_order_id` may be at most {} characters",
+                    CLIENT_ORDER_ID_MAX_LEN,
+                ));
+            }
+        }
+
+        self.validate_legs()
+    }
+
+    fn validate_prices(&self, order_type: &OrderType) -> Result<(), String> {
+        // Order types unknown to this crate are left for Alpaca to validate.
+        if let OrderType::Unknown(_) = order_type {
+            return Ok(());
+        }
+
+        let requirement = |required| if required { "required" } else { "not allowed" };
+
+        if order_type.uses_limit_price() != is_set(&self.limit_price) {
+            return Err(format!(
+                "`limit_price` is {} for {} orders",
+                requirement(order_type.uses_limit_price()),
+                order_type.as_str(),
+            ));
+        }
+        if order_type.uses_stop_price() != is_set(&self.stop_price) {
+            return Err(format!(
+                "`stop_price` is {} for {} orders",
+                requirement(order_type.uses_stop_price()),
+                order_type.as_str(),
+            ));
+        }
+
+        let trail_count = [&self.trail_price, &self.trail_percent]
+            .into_iter()
+            .filter(|value| is_set(value))
+            .count();
+        if *order_type == OrderType::TrailingStop {
+            if trail_count != 1 {
+                return Err(
+                    "trailing_stop orders require exactly one of `trail_price` or `trail_percent`"
+                        .into(),
+                );
+            }
+        } else if trail_count != 0 {
+            return Err(format!(
+                "`trail_price` and `trail_percent` are not allowed for {} orders",
+                order_type.as_str(),
+            ));
+        }
+
+        Ok(())
+    }
+
+    fn validate_legs(&self) -> Result<(), String> {
+        let has_take_profit = self.take_profit.flatten().is_some();
+        let has_stop_loss = self.stop_loss.flatten().is_some();
+        let order_class = self.order_class.clone().flatten().unwrap_or(OrderClass::Simple);
+
+        let (legs_valid, expected) = match order_class {
+            OrderClass::Simple => {
+                (
+                    !has_take_profit && !has_stop_loss,
+                    "no `take_profit` or `stop_loss` legs",
+                )
+            },
+            OrderClass::Bracket | OrderClass::Oco => {
+                (
+                    has_take_profit && has_stop_loss,
+                    "both `take_profit` and `stop_loss` legs",
+                )
+            },
+            OrderClass::Oto => {
+                (
+                    has_take_profit != has_stop_loss,
+                    "exactly one of `take_profit` or `stop_loss` legs",
+                )
+            },
+            // Other order classes are left for Alpaca to validate.
+            OrderClass::Mleg | OrderClass::Unknown(_) => return Ok(()),
+        };
+        if !legs_valid {
+            return Err(format!("{} orders require {}", order_class.as_str(), expected));
+        }
+        if order_class == OrderClass::Oco && self.order_type != Some(OrderType::Limit) {
+            return Err("oco orders must be limit orders".into());
+        }
+
+        Ok(())
+    }
+}
+
+impl Endpoint for CreateOrder {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "orders".into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        Ok(Some(("application/json", serde_json::to_vec(self)?)))
+    }
+}
+
+/// Filters for order statuses.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum OrderStatusFilter {
+    /// Orders which have not yet been filled, canceled or expired.
+    Open,
+    /// Orders which have been filled, canceled or expired.
+    Closed,
+    /// All orders.
+    All,
+}
+
+impl OrderStatusFilter {
+    /// The status filter as a query parameter.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            OrderStatusFilter::Open => "open",
+            OrderStatusFilter::Closed => "closed",
+            OrderStatusFilter::All => "all",
+        }
+    }
+}
+
+impl ParamValue<'static> for OrderStatusFilter {
+    fn as_value(&self) -> Cow<'static, str> {
+        self.as_str().into()
+    }
+}
+
+/// Query for orders.
+#[derive(Debug, Clone, Builder)]
+#[builder(setter(strip_option))]
+pub struct Orders {
+    /// Filter orders by status.
+    #[builder(default)]
+    status: Option<OrderStatusFilter>,
+    /// The maximum number of orders to return (at most 500).
+    #[builder(default)]
+    limit: Option<u64>,
+    /// Only return orders submitted after this time.
+    #[builder(default)]
+    after: Option<DateTime<Utc>>,
+    /// Only return orders submitted until this time.
+    #[builder(default)]
+    until: Option<DateTime<Utc>>,
+    /// The chronological order of the results.
+    #[builder(default)]
+    direction: Option<SortOrder>,
+    /// Roll up multi-leg orders under the `legs` field of the primary order.
+    #[builder(default)]
+    nested: Option<bool>,
+    /// Filter orders by symbol.
+    #[builder(setter(name = "_symbols"), default, private)]
+    symbols: Option<CommaSeparatedList<String>>,
+}
+
+impl Orders {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> OrdersBuilder {
+        OrdersBuilder::default()
+    }
+}
+
+symbol_setters!(OrdersBuilder, optional);
+
+impl Endpoint for Orders {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "orders".into()
+    }
+
+    fn parameters(&self) -> QueryParams<'_> {
+        let mut params = QueryParams::default();
+
+        params
+            .push_opt("status", self.status)
+            .push_opt("limit", self.limit)
+            .push_opt("after", self.after)
+            .push_opt("until", self.until)
+            .push_opt("direction", self.direction)
+            .push_opt("nested", self.nested)
+            .push_opt("symbols", self.symbols.as_ref());
+
+        params
+    }
+}
+
+/// Query for a single order by its ID.
+#[derive(Debug, Clone, Builder)]
+pub struct Order {
+    /// The ID of the order.
+    #[builder(setter(into))]
+    order_id: String,
+    /// Roll up multi-leg orders under the `legs` field of the primary order.
+    #[builder(setter(strip_option), default)]
+    nested: Option<bool>,
+}
+
+impl Order {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> OrderBuilder {
+        OrderBuilder::default()
+    }
+}
+
+impl Endpoint for Order {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("orders/{}", self.order_id).into()
+    }
+
+    fn parameters(&self) -> QueryParams<'_> {
+        let mut params = QueryParams::default();
+
+        params.push_opt("nested", self.nested);
+
+        params
+    }
+}
+
+/// Query for a single order by the client-assigned order ID.
+#[derive(Debug, Clone, Builder)]
+pub struct OrderByClientOrderId {
+    /// The client-assigned ID of the order.
+    #[builder(setter(into))]
+    client_order_id: String,
+}
+
+impl OrderByClientOrderId {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> OrderByClientOrderIdBuilder {
+        OrderByClientOrderIdBuilder::default()
+    }
+}
+
+impl Endpoint for OrderByClientOrderId {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "orders:by_client_order_id".into()
+    }
+
+    fn parameters(&self) -> QueryParams<'_> {
+        let mut params = QueryParams::default();
+
+        params.push("client_order_id", &self.client_order_id);
+
+        params
+    }
+}
+
+/// Replace an open order with updated parameters.
+#[derive(Debug, Clone, Builder, Serialize)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct ReplaceOrder {
+    /// The ID of the order to replace.
+    #[builder(setter(into))]
+    #[serde(skip)]
+    order_id: String,
+    /// The new number of shares.
+    #[builder(setter(strip_option), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    qty: Option<Decimal>,
+    /// The new time in force.
+    #[builder(setter(strip_option), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    time_in_force: Option<TimeInForce>,
+    /// The new limit price.
+    #[builder(setter(strip_option), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    limit_price: Option<Decimal>,
+    /// The new stop price.
+    #[builder(setter(strip_option), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    stop_price: Option<Decimal>,
+    /// The new trail value of a trailing stop order.
+    #[builder(setter(strip_option), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    trail: Option<Decimal>,
+    /// A new client-assigned ID for the order.
+    #[builder(setter(into, strip_option), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    client_order_id: Option<String>,
+}
+
+impl ReplaceOrder {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> ReplaceOrderBuilder {
+        ReplaceOrderBuilder::default()
+    }
+}
+
+impl ReplaceOrderBuilder {
+    fn validate(&self) -> Result<(), String> {
+        let has_change = is_set(&self.qty)
+            || is_set(&self.time_in_force)
+            || is_set(&self.limit_price)
+            || is_set(&self.stop_price)
+            || is_set(&self.trail)
+            || is_set(&self.client_order_id);
+        if !has_change {
+            return Err("at least one order field must be replaced".into());
+        }
+
+        if let Some(Some(client_order_id)) = &self.client_order_id {
+            if client_order_id.len() > CLIENT_ORDER_ID_MAX_LEN {
+                return Err(format!(
+                    "`client_order_id` may be at most {} characters",
+                    CLIENT_ORDER_ID_MAX_LEN,
+                ));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Endpoint for ReplaceOrder {
+    fn method(&self) -> Method {
+        Method::PATCH
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("orders/{}", self.order_id).into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        Ok(Some(("application/json", serde_json::to_vec(self)?)))
+    }
+}
+
+/// Cancel a single open order.
+///
+/// Alpaca answers with an empty body; use `ignore` to query this endpoint.
+#[derive(Debug, Clone, Builder)]
+pub struct CancelOrder {
+    /// The ID of the order to cancel.
+    #[builder(setter(into))]
+    order_id: String,
+}
+
+impl CancelOrder {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> CancelOrderBuilder {
+        CancelOrderBuilder::default()
+    }
+}
+
+impl Endpoint for CancelOrder {
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("orders/{}", self.order_id).into()
+    }
+}
+
+/// Cancel all open orders.
+///
+/// Returns a [`CancelOrderStatus`] for each order a cancellation was attempted for.
+#[derive(Debug, Clone, Copy, Builder)]
+pub struct CancelOrders {}
+
+impl CancelOrders {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> CancelOrdersBuilder {
+        CancelOrdersBuilder::default()
+    }
+}
+
+impl Endpoint for CancelOrders {
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "orders".into()
+    }
+}
+
+/// The outcome of canceling one order as part of [`CancelOrders`].
+#[derive(Debug, Clone, Deserialize)]
+pub struct CancelOrderStatus {
+    /// The ID of the order.
+    pub id: String,
+    /// The HTTP status code of the cancellation request for this order.
+    pub status: u16,
+    /// The order, or the error reported by Alpaca.
+    #[serde(default)]
+    pub body: serde_json::Value,
+}
+
+impl CancelOrderStatus {
+    /// Whether the order was successfully canceled.
+    pub fn is_success(&self) -> bool {
+        (200..300).contains(&self.status)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn order(order_type: OrderType) -> CreateOrderBuilder {
+        let mut builder = CreateOrder::builder();
+        builder
+            .symbol("AAPL")
+            .qty(Decimal::ONE)
+            .side(OrderSide::Buy)
+            .order_type(order_type)
+            .time_in_force(TimeInForce::Gtc);
+        builder
+    }
+
+    fn validation_error(builder: &CreateOrderBuilder) -> String {
+        match builder.build() {
+            Err(CreateOrderBuilderError::ValidationError(message)) => message,
+            other => panic!("expected a validation error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn market_order_is_valid() {
+        order(OrderType::Market).build().unwrap();
+    }
+
+    #[test]
+    fn limit_order_requires_limit_price() {
+        let mut builder = order(OrderType::Limit);
+        assert!(validation_error(&builder).contains("`limit_price` is required"));
+
+        builder.limit_price(Decimal::new(190, 0));
+        builder.build().unwrap();
+    }
+
+    #[test]
+    fn market_order_rejects_limit_price() {
+        let mut builder = order(OrderType::Market);
+        builder.limit_price(Decimal::new(190, 0));
+        assert!(validation_error(&builder).contains("`limit_price` is not allowed"));
+    }
+
+    #[test]
+    fn stop_order_requires_stop_price() {
+        let mut builder = order(OrderType::Stop);
+        assert!(validation_error(&builder).contains("`stop_price` is required"));
+
+        builder.stop_price(Decimal::new(180, 0));
+        builder.build().unwrap();
+    }
+
+    #[test]
+    fn stop_limit_order_requires_both_prices() {
+        let mut builder = order(OrderType::StopLimit);
+        builder.stop_price(Decimal::new(180, 0));
+        assert!(validation_error(&builder).contains("`limit_price` is required"));
+
+        builder.limit_price(Decimal::new(179, 0));
+        builder.build().unwrap();
+    }
+
+    #[test]
+    fn qty_and_notional_are_exclusive() {
+        let mut builder = order(OrderType::Market);
+        builder.notional(Decimal::new(100, 0));
+        assert!(validation_error(&builder).contains("only one of `qty` or `notional`"));
+    }
+
+    #[test]
+    fn qty_or_notional_is_required() {
+        let mut builder = order(OrderType::Market);
+        builder.qty = None;
+        assert!(validation_error(&builder).contains("one of `qty` or `notional` is required"));
+    }
+
+    #[test]
+    fn trailing_stop_requires_one_trail() {
+        let mut builder = order(OrderType::TrailingStop);
+        assert!(validation_error(&builder).contains("exactly one of `trail_price`"));
+
+        builder.trail_percent(Decimal::ONE);
+        builder.build().unwrap();
+
+        builder.trail_price(Decimal::ONE);
+        assert!(validation_error(&builder).contains("exactly one of `trail_price`"));
+    }
+
+    #[test]
+    fn trail_is_not_allowed_on_other_orders() {
+        let mut builder = order(OrderType::Market);
+        builder.trail_price(Decimal::ONE);
+        assert!(validation_error(&builder).contains("are not allowed for market orders"));
+    }
+
+    #[test]
+    fn extended_hours_requires_day_limit_order() {
+        let mut builder = order(OrderType::Limit);
+        builder.limit_price(Decimal::new(190, 0)).extended_hours(true);
+        assert!(validation_error(&builder).contains("`extended_hours` requires"));
+
+        builder.time_in_force(TimeInForce::Day);
+        builder.build().unwrap();
+    }
+
+    #[test]
+    fn client_order_id_length_is_limited() {
+        let mut builder = order(OrderType::Market);
+        builder.client_order_id("x".repeat(CLIENT_ORDER_ID_MAX_LEN + 1));
+        assert!(validation_error(&builder).contains("`client_order_id` may be at most"));
+    }
+
+    #[test]
+    fn bracket_order_requires_both_legs() {
+        let mut builder = order(OrderType::Market);
+        builder.order_class(OrderClass::Bracket);
+        assert!(validation_error(&builder).contains("bracket orders require both"));
+
+        builder.take_profit(TakeProfit::new(Decimal::new(200, 0)));
+        assert!(validation_error(&builder).contains("bracket orders require both"));
+
+        builder.stop_loss(StopLoss::new(Decimal::new(180, 0)));
+        builder.build().unwrap();
+    }
+
+    #[test]
+    fn oto_order_requires_one_leg() {
+        let mut builder = order(OrderType::Market);
+        builder
+            .order_class(OrderClass::Oto)
+            .take_profit(TakeProfit::new(Decimal::new(200, 0)));
+        builder.build().unwrap();
+
+        builder.stop_loss(StopLoss::new(Decimal::new(180, 0)));
+        assert!(validation_error(&builder).contains("oto orders require exactly one"));
+    }
+
+    #[test]
+    fn oco_order_must_be_limit_order() {
+        let mut builder = order(OrderType::Market);
+        builder
+            .order_class(OrderClass::Oco)
+            .take_profit(TakeProfit::new(Decimal::new(200, 0)))
+            .stop_loss(StopLoss::new(Decimal::new(180, 0)));
+        assert!(validation_error(&builder).contains("oco orders must be limit orders"));
+    }
+
+    #[test]
+    fn simple_order_rejects_legs() {
+        let mut builder = order(OrderType::Market);
+        builder.stop_loss(StopLoss::new(Decimal::new(180, 0)));
+        assert!(validation_error(&builder).contains("simple orders require no"));
+    }
+
+    #[test]
+    fn replace_order_requires_a_change() {
+        let mut builder = ReplaceOrder::builder();
+        builder.order_id("order");
+        match builder.build() {
+            Err(ReplaceOrderBuilderError::ValidationError(message)) => {
+                assert!(message.contains("at least one order field"))
+            },
+            other => panic!("expected a validation error, got {:?}", other),
+        }
+
+        builder.limit_price(Decimal::new(190, 0));
+        builder.build().unwrap();
+    }
+
+    #[test]
+    fn replace_order_serializes_only_changes() {
+        let order = ReplaceOrder::builder()
+            .order_id("order")
+            .qty(Decimal::new(5, 0))
+            .build()
+            .unwrap();
+
+        assert_eq!(order.endpoint(), "orders/order");
+        assert_eq!(serde_json::to_value(&order).unwrap(), serde_json::json!({"qty": "5"}));
+    }
+
+    #[test]
+    fn orders_params() {
+        let endpoint = Orders::builder()
+            .status(OrderStatusFilter::Closed)
+            .limit(500)
+            .after("2024-01-01T00:00:00Z".parse().unwrap())
+            .until("2024-01-31T00:00:00Z".parse().unwrap())
+            .direction(SortOrder::Ascending)
+            .nested(true)
+            .symbol("AAPL")
+            .symbols(["BTC/USD", "TSLA"].into_iter())
+            .build()
+            .unwrap();
+
+        assert_eq!(endpoint.endpoint(), "orders");
+        assert_eq!(
+            endpoint.parameters().to_query_string(),
+            "status=closed&limit=500&after=2024-01-01T00%3A00%3A00Z&until=2024-01-31T00%3A00%3A00Z\
+             &direction=asc&nested=true&symbols=AAPL%2CBTC%2FUSD%2CTSLA",
+        );
+    }
+
+    #[test]
+    fn orders_defaults() {
+        let endpoint = Orders::builder().build().unwrap();
+
+        assert_eq!(endpoint.parameters().to_query_string(), "");
+    }
+}
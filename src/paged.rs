@@ -0,0 +1,611 @@
+use std::collections::VecDeque;
+use std::error::Error;
+use async_trait::async_trait;
+use bytes::Bytes;
+use futures_util::stream::{self, Stream, TryStreamExt};
+use http::Response;
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+use url::Url;
+use crate::client::{AsyncClient, Client, RestClient};
+use crate::endpoint::Endpoint;
+use crate::error::ApiError;
+use crate::query;
+use crate::query::{AsyncQuery, Query};
+
+/// The query parameter used to request a specific page.
+const PAGE_TOKEN_PARAM: &str = "page_token";
+/// The response field holding the token for the next page.
+const NEXT_PAGE_TOKEN_FIELD: &str = "next_page_token";
+/// The item field used as the token for the next page by ID-paged endpoints.
+const ITEM_ID_FIELD: &str = "id";
+
+/// How the token for the next page of an endpoint is found.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum PageTokens {
+    /// The response holds the token in a `next_page_token` field next to the items.
+    NextPageToken,
+    /// The `id` of the last item is the token; a page with fewer items than `page_size` is the
+    /// last one.
+    LastItemId {
+        /// The number of items in a full page.
+        page_size: usize,
+    },
+}
+
+/// A trait to indicate that an endpoint is pageable.
+///
+/// Pageable endpoints accept a `page_token` parameter. By default, they return the token for the
+/// next page in a `next_page_token` field next to the items of the page.
+pub trait Pageable {
+    /// The field of the response which holds the items of a page.
+    ///
+    /// If the field holds an array, each element is an item. If it holds an object keyed by
+    /// symbol, each item is a `(symbol, value)` pair; arrays under a symbol are flattened into
+    /// one pair per element. If the response itself is an array, each element is an item and
+    /// the field is not used.
+    fn items_field(&self) -> &'static str;
+
+    /// How the token for the next page is found.
+    fn page_tokens(&self) -> PageTokens {
+        PageTokens::NextPageToken
+    }
+}
+
+impl<E> Pageable for &E
+    where
+        E: Pageable,
+{
+    fn items_field(&self) -> &'static str {
+        (*self).items_field()
+    }
+
+    fn page_tokens(&self) -> PageTokens {
+        (*self).page_tokens()
+    }
+}
+
+/// Pagination options for Alpaca.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Pagination {
+    /// Return all results.
+    ///
+    /// Note that some endpoints may have a server-side limit to the number of results.
+    #[default]
+    All,
+    /// Limit to a number of results.
+    Limit(usize),
+}
+
+impl Pagination {
+    fn is_last_page(self, last_page_size: usize, num_results: usize) -> bool {
+        // Alpaca may return an empty page with a token; stop rather than spin.
+        if last_page_size == 0 {
+            return true;
+        }
+
+        if let Pagination::Limit(limit) = self {
+            return limit <= num_results;
+        }
+
+        false
+    }
+
+    fn remaining(self, num_results: usize) -> Option<usize> {
+        match self {
+            Pagination::All => None,
+            Pagination::Limit(limit) => Some(limit.saturating_sub(num_results)),
+        }
+    }
+}
+
+/// A query modifier that follows page tokens to fetch all results of an endpoint.
+#[derive(Debug)]
+pub struct Paged<E> {
+    endpoint: E,
+    pagination: Pagination,
+}
+
+/// Collect data from a paged endpoint.
+pub fn paged<E>(endpoint: E, pagination: Pagination) -> Paged<E> {
+    Paged {
+        endpoint,
+        pagination,
+    }
+}
+
+/// A single page of results.
+struct Page<T> {
+    items: Vec<T>,
+    next_page_token: Option<String>,
+}
+
+impl<E> Paged<E>
+    where
+        E: Endpoint + Pageable,
+{
+    /// Create an iterator over the results of the paginated query.
+    ///
+    /// Pages are only requested once the items of the previous page have been consumed.
+    pub fn iter<'a, C, T>(&'a self, client: &'a C) -> LazilyPagedIter<'a, E, C, T>
+        where
+            C: Client,
+            T: DeserializeOwned,
+    {
+        LazilyPagedIter {
+            paged: self,
+            client,
+            state: PageState::new(),
+        }
+    }
+
+    /// Create a stream over the results of the paginated query.
+    ///
+    /// Pages are only requested once the items of the previous page have been consumed.
+    pub fn iter_async<'a, C, T>(
+        &'a self,
+        client: &'a C,
+    ) -> impl Stream<Item = Result<T, ApiError<C::Error>>> + Send + 'a
+        where
+            E: Sync,
+            C: AsyncClient + Sync,
+            T: DeserializeOwned + Send + 'a,
+    {
+        stream::unfold(PageState::new(), move |mut state| {
+            async move {
+                loop {
+                    if let Some(item) = state.next_item() {
+                        return Some((Ok(item), state));
+                    }
+                    if state.done {
+                        return None;
+                    }
+
+                    let page = match self.page_url(client, state.next_page_token.as_deref()) {
+                        Ok(url) => self.fetch_page_async(client, url).await,
+                        Err(err) => Err(err),
+                    };
+                    match page {
+                        Ok(page) => state.accept(self.pagination, page),
+                        Err(err) => {
+                            state.done = true;
+                            return Some((Err(err), state));
+                        },
+                    }
+                }
+            }
+        })
+    }
+
+    fn page_url<C>(&self, client: &C, page_token: Option<&str>) -> Result<Url, ApiError<C::Error>>
+        where
+            C: RestClient,
+    {
+        let mut url = query::endpoint_url(&self.endpoint, client)?;
+
+        if let Some(page_token) = page_token {
+            // Replace any token given on the endpoint itself.
+            let pairs: Vec<(String, String)> = url
+                .query_pairs()
+                .filter(|(key, _)| key != PAGE_TOKEN_PARAM)
+                .map(|(key, value)| (key.into_owned(), value.into_owned()))
+                .collect();
+            url.query_pairs_mut()
+                .clear()
+                .extend_pairs(pairs)
+                .append_pair(PAGE_TOKEN_PARAM, page_token);
+        }
+
+        Ok(url)
+    }
+
+    fn parse_page<C, T>(&self, rsp: Response<Bytes>) -> Result<Page<T>, ApiError<C>>
+        where
+            C: Error + Send + Sync + 'static,
+            T: DeserializeOwned,
+    {
+        let mut v = query::response_json(&rsp)?;
+
+        let next_page_token = v
+            .get(NEXT_PAGE_TOKEN_FIELD)
+            .and_then(Value::as_str)
+            .map(Into::into);
+        let raw_items = if v.is_array() {
+            Some(v.take())
+        } else {
+            v.get_mut(self.endpoint.items_field()).map(Value::take)
+        };
+        let raw_items = match raw_items {
+            Some(Value::Array(items)) => items,
+            Some(Value::Object(by_symbol)) => {
+                by_symbol
+                    .into_iter()
+                    .flat_map(|(symbol, value)| {
+                        let values = match value {
+                            Value::Array(values) => values,
+                            value => vec![value],
+                        };
+                        values
+                            .into_iter()
+                            .map(move |value| Value::Array(vec![symbol.clone().into(), value]))
+                    })
+                    .collect()
+            },
+            _ => Vec::new(),
+        };
+        let next_page_token = match self.endpoint.page_tokens() {
+            PageTokens::NextPageToken => next_page_token,
+            PageTokens::LastItemId {
+                page_size,
+            } => {
+                raw_items
+                    .last()
+                    .filter(|_| raw_items.len() >= page_size)
+                    .and_then(|item| item.get(ITEM_ID_FIELD))
+                    .and_then(Value::as_str)
+                    .map(Into::into)
+            },
+        };
+        let items = raw_items
+            .into_iter()
+            .map(|item| serde_json::from_value(item).map_err(ApiError::data_type::<T>))
+            .collect::<Result<_, _>>()?;
+
+        Ok(Page {
+            items,
+            next_page_token,
+        })
+    }
+
+    fn fetch_page<C, T>(&self, client: &C, url: Url) -> Result<Page<T>, ApiError<C::Error>>
+        where
+            C: Client,
+            T: DeserializeOwned,
+    {
+        let (req, data) = query::endpoint_request(&self.endpoint, url)?;
+        let rsp = client.rest(req, data)?;
+        self.parse_page(rsp)
+    }
+
+    async fn fetch_page_async<C, T>(
+        &self,
+        client: &C,
+        url: Url,
+    ) -> Result<Page<T>, ApiError<C::Error>>
+        where
+            C: AsyncClient + Sync,
+            T: DeserializeOwned,
+    {
+        let (req, data) = query::endpoint_request(&self.endpoint, url)?;
+        let rsp = client.rest_async(req, data).await?;
+        self.parse_page(rsp)
+    }
+}
+
+impl<E, T, C> Query<Vec<T>, C> for Paged<E>
+    where
+        E: Endpoint + Pageable,
+        T: DeserializeOwned,
+        C: Client,
+{
+    fn query(&self, client: &C) -> Result<Vec<T>, ApiError<C::Error>> {
+        self.iter(client).collect()
+    }
+}
+
+#[async_trait]
+impl<E, T, C> AsyncQuery<Vec<T>, C> for Paged<E>
+    where
+        E: Endpoint + Pageable + Sync,
+        T: DeserializeOwned + Send + 'static,
+        C: AsyncClient + Sync,
+{
+    async fn query_async(&self, client: &C) -> Result<Vec<T>, ApiError<C::Error>> {
+        self.iter_async(client).try_collect().await
+    }
+}
+
+/// The progress through a paginated result.
+struct PageState<T> {
+    items: VecDeque<T>,
+    next_page_token: Option<String>,
+    num_results: usize,
+    done: bool,
+}
+
+impl<T> PageState<T> {
+    fn new() -> Self {
+        PageState {
+            items: VecDeque::new(),
+            next_page_token: None,
+            num_results: 0,
+            done: false,
+        }
+    }
+
+    fn next_item(&mut self) -> Option<T> {
+        let item = self.items.pop_front()?;
+        self.num_results += 1;
+        Some(item)
+    }
+
+    fn accept(&mut self, pagination: Pagination, page: Page<T>) {
+        let page_size = page.items.len();
+        let fetched = self.num_results + page_size;
+        let remaining = pagination.remaining(self.num_results).unwrap_or(usize::MAX);
+
+        self.items.extend(page.items.into_iter().take(remaining));
+        self.done = page.next_page_token.is_none() || pagination.is_last_page(page_size, fetched);
+        self.next_page_token = page.next_page_token;
+    }
+}
+
+/// An iterator which yields items from a paginated result, fetching pages lazily.
+pub struct LazilyPagedIter<'a, E, C, T> {
+    paged: &'a Paged<E>,
+    client: &'a C,
+    state: PageState<T>,
+}
+
+impl<'a, E, C, T> Iterator for LazilyPagedIter<'a, E, C, T>
+    where
+        E: Endpoint + Pageable,
+        C: Client,
+        T: DeserializeOwned,
+{
+    type Item = Result<T, ApiError<C::Error>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(item) = self.state.next_item() {
+                return Some(Ok(item));
+            }
+            if self.state.done {
+                return None;
+            }
+
+            let page = self
+                .paged
+                .page_url(self.client, self.state.next_page_token.as_deref())
+                .and_then(|url| self.paged.fetch_page(self.client, url));
+            match page {
+                Ok(page) => self.state.accept(self.paged.pagination, page),
+                Err(err) => {
+                    self.state.done = true;
+                    return Some(Err(err));
+                },
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+    use std::cell::RefCell;
+    use http::Method;
+    use http::request::Builder as RequestBuilder;
+    use serde_json::json;
+    use thiserror::Error;
+    use crate::params::QueryParams;
+    use super::*;
+
+    #[derive(Debug, Error)]
+    #[error("mock client error")]
+    struct MockError;
+
+    /// A client answering with canned pages, keyed by the requested page token.
+    struct MockClient {
+        pages: Vec<(Option<&'static str>, Value)>,
+        requested: RefCell<Vec<Option<String>>>,
+    }
+
+    impl MockClient {
+        fn new(pages: Vec<(Option<&'static str>, Value)>) -> Self {
+            MockClient {
+                pages,
+                requested: RefCell::new(Vec::new()),
+            }
+        }
+    }
+
+    impl RestClient for MockClient {
+        type Error = MockError;
+
+        fn rest_endpoint(&self, endpoint: &str) -> Result<Url, ApiError<Self::Error>> {
+            Ok(Url::parse("https://api.test/v2/")?.join(endpoint)?)
+        }
+    }
+
+    impl Client for MockClient {
+        fn rest(
+            &self,
+            request: RequestBuilder,
+            _: Vec<u8>,
+        ) -> Result<Response<Bytes>, ApiError<Self::Error>> {
+            let url = Url::parse(&request.uri_ref().unwrap().to_string()).unwrap();
+            let page_token = url
+                .query_pairs()
+                .find(|(key, _)| key == PAGE_TOKEN_PARAM)
+                .map(|(_, value)| value.into_owned());
+            self.requested.borrow_mut().push(page_token.clone());
+
+            let (_, page) = self
+                .pages
+                .iter()
+                .find(|(token, _)| token.map(Into::into) == page_token)
+                .expect("an unexpected page was requested");
+            Ok(Response::builder()
+                .status(200)
+                .body(serde_json::to_vec(page).unwrap().into())
+                .unwrap())
+        }
+    }
+
+    struct Items {
+        page_tokens: PageTokens,
+        page_token: Option<&'static str>,
+    }
+
+    impl Items {
+        fn new() -> Self {
+            Items {
+                page_tokens: PageTokens::NextPageToken,
+                page_token: None,
+            }
+        }
+    }
+
+    impl Endpoint for Items {
+        fn method(&self) -> Method {
+            Method::GET
+        }
+
+        fn endpoint(&self) -> Cow<'static, str> {
+            "items".into()
+        }
+
+        fn parameters(&self) -> QueryParams<'_> {
+            let mut params = QueryParams::default();
+
+            params
+                .push("limit", 2u64)
+                .push_opt("page_token", self.page_token);
+
+            params
+        }
+    }
+
+    impl Pageable for Items {
+        fn items_field(&self) -> &'static str {
+            "items"
+        }
+
+        fn page_tokens(&self) -> PageTokens {
+            self.page_tokens
+        }
+    }
+
+    #[test]
+    fn follows_next_page_tokens() {
+        let client = MockClient::new(vec![
+            (None, json!({"items": [1, 2], "next_page_token": "a"})),
+            (Some("a"), json!({"items": [3, 4], "next_page_token": "b"})),
+            (Some("b"), json!({"items": [5], "next_page_token": null})),
+        ]);
+
+        let items: Vec<u64> = paged(Items::new(), Pagination::All).query(&client).unwrap();
+        assert_eq!(items, [1, 2, 3, 4, 5]);
+        assert_eq!(
+            *client.requested.borrow(),
+            [None, Some("a".into()), Some("b".into())],
+        );
+    }
+
+    #[test]
+    fn replaces_page_token_of_endpoint() {
+        let client = MockClient::new(vec![
+            (Some("start"), json!({"items": [1, 2], "next_page_token": "a"})),
+            (Some("a"), json!({"items": [3]})),
+        ]);
+        let endpoint = Items {
+            page_token: Some("start"),
+            ..Items::new()
+        };
+
+        let items: Vec<u64> = paged(endpoint, Pagination::All).query(&client).unwrap();
+        assert_eq!(items, [1, 2, 3]);
+    }
+
+    #[test]
+    fn stops_at_empty_page() {
+        let client = MockClient::new(vec![
+            (None, json!({"items": [1, 2], "next_page_token": "a"})),
+            (Some("a"), json!({"items": [], "next_page_token": "b"})),
+        ]);
+
+        let items: Vec<u64> = paged(Items::new(), Pagination::All).query(&client).unwrap();
+        assert_eq!(items, [1, 2]);
+        assert_eq!(client.requested.borrow().len(), 2);
+    }
+
+    #[test]
+    fn stops_at_missing_items() {
+        let client = MockClient::new(vec![(None, json!({"items": null, "next_page_token": null}))]);
+
+        let items: Vec<u64> = paged(Items::new(), Pagination::All).query(&client).unwrap();
+        assert!(items.is_empty());
+    }
+
+    #[test]
+    fn stops_mid_page_at_limit() {
+        let client = MockClient::new(vec![
+            (None, json!({"items": [1, 2], "next_page_token": "a"})),
+            (Some("a"), json!({"items": [3, 4], "next_page_token": "b"})),
+            (Some("b"), json!({"items": [5, 6], "next_page_token": null})),
+        ]);
+
+        let items: Vec<u64> = paged(Items::new(), Pagination::Limit(3)).query(&client).unwrap();
+        assert_eq!(items, [1, 2, 3]);
+        assert_eq!(client.requested.borrow().len(), 2);
+    }
+
+    #[test]
+    fn fetches_pages_lazily() {
+        let client = MockClient::new(vec![
+            (None, json!({"items": [1, 2], "next_page_token": "a"})),
+            (Some("a"), json!({"items": [3]})),
+        ]);
+        let paged = paged(Items::new(), Pagination::All);
+
+        let mut iter = paged.iter::<_, u64>(&client);
+        assert_eq!(iter.next().unwrap().unwrap(), 1);
+        assert_eq!(iter.next().unwrap().unwrap(), 2);
+        assert_eq!(client.requested.borrow().len(), 1);
+        assert_eq!(iter.next().unwrap().unwrap(), 3);
+        assert!(iter.next().is_none());
+        assert_eq!(client.requested.borrow().len(), 2);
+    }
+
+    #[test]
+    fn pages_bare_arrays_by_last_item_id() {
+        let client = MockClient::new(vec![
+            (None, json!([{"id": "1"}, {"id": "2"}])),
+            (Some("2"), json!([{"id": "3"}, {"id": "4"}])),
+            (Some("4"), json!([{"id": "5"}])),
+        ]);
+        let endpoint = Items {
+            page_tokens: PageTokens::LastItemId {
+                page_size: 2,
+            },
+            ..Items::new()
+        };
+
+        let items: Vec<Value> = paged(endpoint, Pagination::All).query(&client).unwrap();
+        let ids: Vec<_> = items.iter().map(|item| item["id"].as_str().unwrap()).collect();
+        assert_eq!(ids, ["1", "2", "3", "4", "5"]);
+        assert_eq!(client.requested.borrow().len(), 3);
+    }
+
+    #[test]
+    fn pages_items_keyed_by_symbol() {
+        let client = MockClient::new(vec![
+            (None, json!({"items": {"AAPL": [1, 2], "TSLA": [3]}, "next_page_token": "a"})),
+            (Some("a"), json!({"items": {"TSLA": 4}})),
+        ]);
+
+        let items: Vec<(String, u64)> = paged(Items::new(), Pagination::All)
+            .query(&client)
+            .unwrap();
+        assert_eq!(
+            items,
+            [
+                ("AAPL".into(), 1),
+                ("AAPL".into(), 2),
+                ("TSLA".into(), 3),
+                ("TSLA".into(), 4),
+            ],
+        );
+    }
+}
@@ -1,4 +1,6 @@
 use std::borrow::Cow;
+use chrono::{DateTime, NaiveDate, SecondsFormat, Utc};
+use rust_decimal::Decimal;
 use url::Url;
 
 /// A trait representing a parameter value.
@@ -60,6 +62,23 @@ impl ParamValue<'static> for f64 {
     }
 }
 
+impl ParamValue<'static> for Decimal {
+    fn as_value(&self) -> Cow<'static, str> {
+        self.to_string().into()
+    }
+}
+
+impl ParamValue<'static> for DateTime<Utc> {
+    fn as_value(&self) -> Cow<'static, str> {
+        self.to_rfc3339_opts(SecondsFormat::AutoSi, true).into()
+    }
+}
+
+impl ParamValue<'static> for NaiveDate {
+    fn as_value(&self) -> Cow<'static, str> {
+        self.format("%Y-%m-%d").to_string().into()
+    }
+}
 
 /// A structure for query parameters.
 #[derive(Debug, Default, Clone)]
@@ -111,3 +130,13 @@ impl<'a> QueryParams<'a> {
         pairs.extend_pairs(self.params.iter());
     }
 }
+
+#[cfg(test)]
+impl QueryParams<'_> {
+    /// The parameters as a URL-encoded query string.
+    pub(crate) fn to_query_string(&self) -> String {
+        url::form_urlencoded::Serializer::new(String::new())
+            .extend_pairs(self.params.iter())
+            .finish()
+    }
+}
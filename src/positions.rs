@@ -0,0 +1,430 @@
+use std::borrow::Cow;
+use derive_builder::Builder;
+use http::Method;
+use rust_decimal::Decimal;
+use serde::Deserialize;
+use thiserror::Error;
+use crate::endpoint::Endpoint;
+use crate::error::AlpacaErrorBody;
+use crate::models::Order;
+use crate::params::QueryParams;
+
+/// Query for all open positions.
+#[derive(Debug, Clone, Copy, Builder)]
+pub struct Positions {}
+
+impl Positions {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> PositionsBuilder {
+        PositionsBuilder::default()
+    }
+}
+
+impl Endpoint for Positions {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "positions".into()
+    }
+}
+
+/// Query for a single open position.
+#[derive(Debug, Clone, Builder)]
+pub struct Position {
+    /// The symbol or asset ID of the position.
+    #[builder(setter(into))]
+    symbol_or_asset_id: String,
+}
+
+impl Position {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> PositionBuilder {
+        PositionBuilder::default()
+    }
+}
+
+impl Endpoint for Position {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("positions/{}", self.symbol_or_asset_id).into()
+    }
+}
+
+/// Close (liquidate) a single open position.
+///
+/// Returns the [`Order`] submitted to close the position. The entire position is closed unless
+/// one of `qty` or `percentage` is given.
+#[derive(Debug, Clone, Builder)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct ClosePosition {
+    /// The symbol or asset ID of the position.
+    #[builder(setter(into))]
+    symbol_or_asset_id: String,
+    /// The number of shares to liquidate.
+    #[builder(setter(strip_option), default)]
+    qty: Option<Decimal>,
+    /// The percentage of the position to liquidate.
+    #[builder(setter(strip_option), default)]
+    percentage: Option<Decimal>,
+}
+
+impl ClosePosition {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> ClosePositionBuilder {
+        ClosePositionBuilder::default()
+    }
+}
+
+impl ClosePositionBuilder {
+    fn validate(&self) -> Result<(), String> {
+        if let (Some(Some(_)), Some(Some(_))) = (&self.qty, &self.percentage) {
+            return Err("only one of `qty` or `percentage` may be given".into());
+        }
+
+        if let Some(Some(percentage)) = self.percentage {
+            if percentage <= Decimal::ZERO || percentage > Decimal::ONE_HUNDRED {
+                return Err("`percentage` must be greater than 0 and at most 100".into());
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Endpoint for ClosePosition {
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("positions/{}", self.symbol_or_asset_id).into()
+    }
+
+    fn parameters(&self) -> QueryParams<'_> {
+        let mut params = QueryParams::default();
+
+        params
+            .push_opt("qty", self.qty)
+            .push_opt("percentage", self.percentage);
+
+        params
+    }
+}
+
+/// Close (liquidate) all open positions.
+///
+/// Returns a [`ClosePositionStatus`] for each position a liquidation was attempted for.
+#[derive(Debug, Clone, Copy, Builder)]
+#[builder(setter(strip_option))]
+pub struct ClosePositions {
+    /// Cancel all open orders before liquidating.
+    #[builder(default)]
+    cancel_orders: Option<bool>,
+}
+
+impl ClosePositions {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> ClosePositionsBuilder {
+        ClosePositionsBuilder::default()
+    }
+}
+
+impl Endpoint for ClosePositions {
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "positions".into()
+    }
+
+    fn parameters(&self) -> QueryParams<'_> {
+        let mut params = QueryParams::default();
+
+        params.push_opt("cancel_orders", self.cancel_orders);
+
+        params
+    }
+}
+
+#[derive(Deserialize)]
+struct RawClosePositionStatus {
+    symbol: String,
+    status: u16,
+    #[serde(default)]
+    body: serde_json::Value,
+}
+
+/// The outcome of closing one position as part of [`ClosePositions`].
+#[derive(Debug, Clone, Deserialize)]
+#[serde(from = "RawClosePositionStatus")]
+pub struct ClosePositionStatus {
+    /// The symbol of the position.
+    pub symbol: String,
+    /// The HTTP status code of the liquidation request for this position.
+    pub status: u16,
+    /// The order submitted to close the position or the error reported by Alpaca.
+    pub result: Result<Order, ClosePositionError>,
+}
+
+/// Why closing one position as part of [`ClosePositions`] failed.
+#[derive(Debug, Clone, Error)]
+#[non_exhaustive]
+pub enum ClosePositionError {
+    /// Alpaca returned a structured error.
+    #[error("alpaca error: {}", _0)]
+    Alpaca(AlpacaErrorBody),
+    /// Alpaca returned a body which is neither an order nor a structured error.
+    #[error("unrecognized response with status {}: {}", status, body)]
+    Unrecognized {
+        /// The HTTP status code of the liquidation request.
+        status: u16,
+        /// The body as returned by Alpaca.
+        body: serde_json::Value,
+    },
+}
+
+impl ClosePositionStatus {
+    /// Whether the liquidation order was successfully submitted.
+    pub fn is_success(&self) -> bool {
+        self.result.is_ok()
+    }
+}
+
+impl From<RawClosePositionStatus> for ClosePositionStatus {
+    fn from(raw: RawClosePositionStatus) -> Self {
+        let status = raw.status;
+        let unrecognized = |body| {
+            ClosePositionError::Unrecognized {
+                status,
+                body,
+            }
+        };
+        // One malformed body must not fail the results of every other position.
+        let result = if (200..300).contains(&status) {
+            serde_json::from_value(raw.body.clone()).map_err(|_| unrecognized(raw.body))
+        } else {
+            Err(match serde_json::from_value(raw.body.clone()) {
+                Ok(body) => ClosePositionError::Alpaca(body),
+                Err(_) => unrecognized(raw.body),
+            })
+        };
+
+        ClosePositionStatus {
+            symbol: raw.symbol,
+            status,
+            result,
+        }
+    }
+}
+
+/// Exercise a held option contract.
+///
+/// Alpaca answers with an empty body; use `ignore` to query this endpoint.
+#[derive(Debug, Clone, Builder)]
+pub struct ExercisePosition {
+    /// The symbol or contract ID of the option position.
+    #[builder(setter(into))]
+    symbol_or_contract_id: String,
+}
+
+impl ExercisePosition {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> ExercisePositionBuilder {
+        ExercisePositionBuilder::default()
+    }
+}
+
+impl Endpoint for ExercisePosition {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("positions/{}/exercise", self.symbol_or_contract_id).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+    use super::*;
+
+    fn order() -> serde_json::Value {
+        json!({
+            "id": "61e69015-8549-4bfd-b9c3-01e75843f47d",
+            "client_order_id": "eb9e2aaa-f71a-4f51-b5b4-52a6c565dad4",
+            "created_at": "2024-01-02T14:30:00Z",
+            "asset_id": "b0b6dd9d-8b9b-48a9-ba46-b9d54906e415",
+            "symbol": "AAPL",
+            "asset_class": "us_equity",
+            "qty": "10",
+            "filled_qty": "0",
+            "order_class": "",
+            "type": "market",
+            "side": "sell",
+            "time_in_force": "day",
+            "status": "accepted",
+            "extended_hours": false,
+        })
+    }
+
+    #[test]
+    fn close_position_rejects_qty_and_percentage() {
+        let mut builder = ClosePosition::builder();
+        builder
+            .symbol_or_asset_id("AAPL")
+            .qty(Decimal::ONE)
+            .percentage(Decimal::new(50, 0));
+        assert!(builder.build().is_err());
+    }
+
+    #[test]
+    fn close_position_limits_percentage() {
+        for percentage in [Decimal::ZERO, Decimal::new(101, 0)] {
+            let mut builder = ClosePosition::builder();
+            builder.symbol_or_asset_id("AAPL").percentage(percentage);
+            assert!(builder.build().is_err());
+        }
+
+        ClosePosition::builder()
+            .symbol_or_asset_id("AAPL")
+            .percentage(Decimal::ONE_HUNDRED)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn close_position_statuses_are_per_symbol() {
+        let statuses: Vec<ClosePositionStatus> = serde_json::from_value(json!([
+            {
+                "symbol": "AAPL",
+                "status": 200,
+                "body": order(),
+            },
+            {
+                "symbol": "TSLA",
+                "status": 403,
+                "body": {
+                    "code": 40310000,
+                    "message": "insufficient qty available for order",
+                },
+            },
+        ]))
+        .unwrap();
+
+        assert_eq!(statuses.len(), 2);
+        assert!(statuses[0].is_success());
+        assert_eq!(statuses[0].result.as_ref().unwrap().symbol, "AAPL");
+
+        assert_eq!(statuses[1].symbol, "TSLA");
+        assert_eq!(statuses[1].status, 403);
+        match statuses[1].result.as_ref().unwrap_err() {
+            ClosePositionError::Alpaca(error) => {
+                assert_eq!(error.code, Some(40310000));
+                assert_eq!(error.message, "insufficient qty available for order");
+            },
+            error => panic!("expected an Alpaca error, got {:?}", error),
+        }
+    }
+
+    #[test]
+    fn close_position_statuses_keep_unrecognized_errors() {
+        let statuses: Vec<ClosePositionStatus> = serde_json::from_value(json!([
+            {
+                "symbol": "AAPL",
+                "status": 500,
+                "body": {"error": "internal"},
+            },
+            {
+                "symbol": "TSLA",
+                "status": 422,
+            },
+            {
+                "symbol": "MSFT",
+                "status": 200,
+                "body": order(),
+            },
+        ]))
+        .unwrap();
+
+        match statuses[0].result.as_ref().unwrap_err() {
+            ClosePositionError::Unrecognized {
+                status,
+                body,
+            } => {
+                assert_eq!(*status, 500);
+                assert_eq!(*body, json!({"error": "internal"}));
+            },
+            error => panic!("expected an unrecognized error, got {:?}", error),
+        }
+        assert!(matches!(
+            statuses[1].result,
+            Err(ClosePositionError::Unrecognized {
+                status: 422,
+                body: serde_json::Value::Null,
+            }),
+        ));
+        assert!(statuses[2].is_success());
+    }
+
+    #[test]
+    fn close_position_statuses_keep_malformed_orders() {
+        let statuses: Vec<ClosePositionStatus> = serde_json::from_value(json!([
+            {
+                "symbol": "AAPL",
+                "status": 200,
+                "body": order(),
+            },
+            {
+                "symbol": "TSLA",
+                "status": 200,
+                "body": {"id": "not an order"},
+            },
+        ]))
+        .unwrap();
+
+        assert!(statuses[0].is_success());
+        assert_eq!(statuses[1].symbol, "TSLA");
+        match statuses[1].result.as_ref().unwrap_err() {
+            ClosePositionError::Unrecognized {
+                status,
+                body,
+            } => {
+                assert_eq!(*status, 200);
+                assert_eq!(*body, json!({"id": "not an order"}));
+            },
+            error => panic!("expected an unrecognized response, got {:?}", error),
+        }
+    }
+
+    #[test]
+    fn close_position_errors_display() {
+        let error = ClosePositionError::Alpaca(
+            serde_json::from_value(json!({
+                "code": 40310000,
+                "message": "insufficient qty available for order",
+            }))
+            .unwrap(),
+        );
+        assert_eq!(
+            error.to_string(),
+            "alpaca error: insufficient qty available for order (code 40310000)",
+        );
+
+        let error = ClosePositionError::Unrecognized {
+            status: 500,
+            body: json!({"error": "internal"}),
+        };
+        assert_eq!(
+            error.to_string(),
+            r#"unrecognized response with status 500: {"error":"internal"}"#,
+        );
+        let _: &dyn std::error::Error = &error;
+    }
+}
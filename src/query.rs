@@ -1,6 +1,12 @@
-use http::Uri;
+use std::error::Error;
+use async_trait::async_trait;
+use bytes::Bytes;
+use http::{header, Request, Response, Uri};
+use http::request::Builder as RequestBuilder;
+use serde_json::Value;
 use url::Url;
-use crate::client::Client;
+use crate::client::{AsyncClient, Client, RestClient};
+use crate::endpoint::Endpoint;
 use crate::error::ApiError;
 
 pub fn url_to_http_uri(url: Url) -> Uri {
@@ -9,6 +15,64 @@ pub fn url_to_http_uri(url: Url) -> Uri {
         .expect("failed to parse a url::Url as an http::Uri")
 }
 
+/// The URL of an endpoint, including its query parameters.
+pub(crate) fn endpoint_url<E, C>(endpoint: &E, client: &C) -> Result<Url, ApiError<C::Error>>
+    where
+        E: Endpoint,
+        C: RestClient,
+{
+    let mut url = endpoint
+        .url_base()
+        .endpoint_for(client, &endpoint.endpoint())?;
+    endpoint.parameters().add_to_url(&mut url);
+    Ok(url)
+}
+
+/// Build the request for an endpoint at the given URL, along with its body.
+pub(crate) fn endpoint_request<E, C>(
+    endpoint: &E,
+    url: Url,
+) -> Result<(RequestBuilder, Vec<u8>), ApiError<C>>
+    where
+        E: Endpoint,
+        C: Error + Send + Sync + 'static,
+{
+    let req = Request::builder()
+        .method(endpoint.method())
+        .uri(url_to_http_uri(url));
+    Ok(if let Some((mime, data)) = endpoint.body()? {
+        (req.header(header::CONTENT_TYPE, mime), data)
+    } else {
+        (req, Vec::new())
+    })
+}
+
+/// Turn a response with an unsuccessful status into an error.
+pub(crate) fn check_response<C>(rsp: &Response<Bytes>) -> Result<(), ApiError<C>>
+    where
+        C: Error + Send + Sync + 'static,
+{
+    let status = rsp.status();
+    if status == http::StatusCode::MOVED_PERMANENTLY {
+        return Err(ApiError::moved_permanently(
+            rsp.headers().get(header::LOCATION),
+        ));
+    } else if !status.is_success() {
+        return Err(ApiError::from_response(status, rsp.body()));
+    }
+
+    Ok(())
+}
+
+/// Check a response and parse its body as JSON.
+pub(crate) fn response_json<C>(rsp: &Response<Bytes>) -> Result<Value, ApiError<C>>
+    where
+        C: Error + Send + Sync + 'static,
+{
+    check_response(rsp)?;
+    serde_json::from_slice(rsp.body())
+        .map_err(|_| ApiError::server_error(rsp.status(), rsp.body()))
+}
 
 /// A trait which represents a query which may be made to a Alpaca client.
 pub trait Query<T, C>
@@ -18,3 +82,13 @@ pub trait Query<T, C>
     /// Perform the query against the client.
     fn query(&self, client: &C) -> Result<T, ApiError<C::Error>>;
 }
+
+/// A trait which represents an asynchronous query which may be made to a Alpaca client.
+#[async_trait]
+pub trait AsyncQuery<T, C>
+    where
+        C: AsyncClient + Sync,
+{
+    /// Perform the query asynchronously against the client.
+    async fn query_async(&self, client: &C) -> Result<T, ApiError<C::Error>>;
+}
@@ -0,0 +1,241 @@
+use std::fmt;
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::thread;
+use std::time::{Duration, Instant};
+use http::HeaderMap;
+
+/// The header Alpaca uses to report the number of requests allowed per minute.
+const RATE_LIMIT_LIMIT: &str = "x-ratelimit-limit";
+/// The header Alpaca uses to report the number of requests left in the current window.
+const RATE_LIMIT_REMAINING: &str = "x-ratelimit-remaining";
+
+/// The default number of requests Alpaca allows per minute.
+const DEFAULT_REQUESTS_PER_MINUTE: u32 = 200;
+
+/// A token bucket limiting the rate of requests made to Alpaca.
+///
+/// Cloning the limiter shares the bucket, so one limiter may be attached to any number of
+/// clients (and their clones) using the same API key. The bucket calibrates itself from the
+/// `X-RateLimit-Limit` and `X-RateLimit-Remaining` headers of each response.
+#[derive(Clone)]
+pub struct RateLimiter {
+    bucket: Arc<Mutex<Bucket>>,
+}
+
+struct Bucket {
+    /// The number of requests allowed per minute.
+    limit: u32,
+    /// The number of requests which may currently be made.
+    tokens: f64,
+    /// When `tokens` was last refilled.
+    refilled_at: Instant,
+}
+
+impl Bucket {
+    fn refill(&mut self) {
+        let now = Instant::now();
+        let elapsed = now.duration_since(self.refilled_at).as_secs_f64();
+        self.tokens = (self.tokens + elapsed * self.per_second()).min(self.limit as f64);
+        self.refilled_at = now;
+    }
+
+    fn per_second(&self) -> f64 {
+        self.limit as f64 / 60.
+    }
+
+    /// Take a token, or return how long to wait until one is available.
+    fn take(&mut self) -> Result<(), Duration> {
+        self.refill();
+        if self.tokens >= 1. {
+            self.tokens -= 1.;
+            Ok(())
+        } else {
+            Err(Duration::from_secs_f64((1. - self.tokens) / self.per_second()))
+        }
+    }
+}
+
+impl RateLimiter {
+    /// Create a limiter allowing `limit` requests per minute.
+    ///
+    /// The bucket starts full. A limit of zero is treated as one request per minute.
+    pub fn per_minute(limit: u32) -> Self {
+        let limit = limit.max(1);
+        RateLimiter {
+            bucket: Arc::new(Mutex::new(Bucket {
+                limit,
+                tokens: limit as f64,
+                refilled_at: Instant::now(),
+            })),
+        }
+    }
+
+    /// The number of requests allowed per minute.
+    pub fn limit(&self) -> u32 {
+        self.lock().limit
+    }
+
+    /// The number of requests which may be made right now without waiting.
+    pub fn available(&self) -> u32 {
+        let mut bucket = self.lock();
+        bucket.refill();
+        bucket.tokens as u32
+    }
+
+    /// Block until a request may be made.
+    pub(crate) fn acquire(&self) {
+        while let Err(wait) = self.try_acquire() {
+            thread::sleep(wait);
+        }
+    }
+
+    /// Wait until a request may be made.
+    pub(crate) async fn acquire_async(&self) {
+        while let Err(wait) = self.try_acquire() {
+            tokio::time::sleep(wait).await;
+        }
+    }
+
+    /// Take a token without holding the lock any longer than needed.
+    fn try_acquire(&self) -> Result<(), Duration> {
+        self.lock().take()
+    }
+
+    /// Calibrate the bucket from the rate limit headers of a response.
+    pub(crate) fn update(&self, headers: &HeaderMap) {
+        let header = |name| {
+            headers
+                .get(name)
+                .and_then(|value| value.to_str().ok())
+                .and_then(|value| value.trim().parse::<u32>().ok())
+        };
+
+        let mut bucket = self.lock();
+        bucket.refill();
+        if let Some(limit) = header(RATE_LIMIT_LIMIT).filter(|&limit| limit > 0) {
+            bucket.limit = limit;
+            bucket.tokens = bucket.tokens.min(limit as f64);
+        }
+        // Other users of the same key consume the same budget; never exceed Alpaca's view of it.
+        if let Some(remaining) = header(RATE_LIMIT_REMAINING) {
+            bucket.tokens = bucket.tokens.min(remaining as f64);
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Bucket> {
+        // The bucket is always left consistent, so a poisoned lock is still usable.
+        self.bucket
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+impl Default for RateLimiter {
+    fn default() -> Self {
+        RateLimiter::per_minute(DEFAULT_REQUESTS_PER_MINUTE)
+    }
+}
+
+impl fmt::Debug for RateLimiter {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("RateLimiter")
+            .field("limit", &self.limit())
+            .field("available", &self.available())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::HeaderValue;
+    use super::*;
+
+    fn drain(limiter: &RateLimiter) {
+        while limiter.try_acquire().is_ok() {}
+    }
+
+    #[test]
+    fn starts_full() {
+        let limiter = RateLimiter::per_minute(120);
+
+        assert_eq!(limiter.limit(), 120);
+        assert_eq!(limiter.available(), 120);
+    }
+
+    #[test]
+    fn zero_limit_allows_one_request() {
+        let limiter = RateLimiter::per_minute(0);
+
+        assert_eq!(limiter.limit(), 1);
+        assert!(limiter.try_acquire().is_ok());
+        assert!(limiter.try_acquire().is_err());
+    }
+
+    #[test]
+    fn waits_for_the_next_token() {
+        let limiter = RateLimiter::per_minute(60);
+        drain(&limiter);
+
+        assert_eq!(limiter.available(), 0);
+        let wait = limiter.try_acquire().unwrap_err();
+        assert!(wait > Duration::ZERO && wait <= Duration::from_secs(1), "{:?}", wait);
+    }
+
+    #[test]
+    fn refills_over_time() {
+        let limiter = RateLimiter::per_minute(60);
+        drain(&limiter);
+
+        limiter.lock().refilled_at -= Duration::from_secs(10);
+        assert_eq!(limiter.available(), 10);
+
+        limiter.lock().refilled_at -= Duration::from_secs(3600);
+        assert_eq!(limiter.available(), 60);
+    }
+
+    #[test]
+    fn clones_share_the_bucket() {
+        let limiter = RateLimiter::per_minute(2);
+        let clone = limiter.clone();
+
+        assert!(limiter.try_acquire().is_ok());
+        assert!(clone.try_acquire().is_ok());
+        assert!(limiter.try_acquire().is_err());
+        assert_eq!(clone.available(), 0);
+    }
+
+    #[test]
+    fn calibrates_from_headers() {
+        let limiter = RateLimiter::per_minute(200);
+        let mut headers = HeaderMap::new();
+        headers.insert(RATE_LIMIT_LIMIT, HeaderValue::from_static("100"));
+        headers.insert(RATE_LIMIT_REMAINING, HeaderValue::from_static("7"));
+
+        limiter.update(&headers);
+        assert_eq!(limiter.limit(), 100);
+        assert_eq!(limiter.available(), 7);
+    }
+
+    #[test]
+    fn remaining_never_adds_tokens() {
+        let limiter = RateLimiter::per_minute(200);
+        drain(&limiter);
+        let mut headers = HeaderMap::new();
+        headers.insert(RATE_LIMIT_REMAINING, HeaderValue::from_static("150"));
+
+        limiter.update(&headers);
+        assert_eq!(limiter.available(), 0);
+    }
+
+    #[test]
+    fn ignores_invalid_headers() {
+        let limiter = RateLimiter::per_minute(200);
+        let mut headers = HeaderMap::new();
+        headers.insert(RATE_LIMIT_LIMIT, HeaderValue::from_static("0"));
+        headers.insert(RATE_LIMIT_REMAINING, HeaderValue::from_static("many"));
+
+        limiter.update(&headers);
+        assert_eq!(limiter.limit(), 200);
+        assert_eq!(limiter.available(), 200);
+    }
+}
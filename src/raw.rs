@@ -1,9 +1,9 @@
-use http::{header, Request};
-use crate::client::Client;
+use async_trait::async_trait;
+use crate::client::{AsyncClient, Client};
 use crate::endpoint::Endpoint;
 use crate::error::ApiError;
 use crate::query;
-use crate::query::Query;
+use crate::query::{AsyncQuery, Query};
 
 /// A query modifier that returns the raw data from the endpoint.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -24,39 +24,83 @@ impl<E, C> Query<Vec<u8>, C> for Raw<E>
         C: Client,
 {
     fn query(&self, client: &C) -> Result<Vec<u8>, ApiError<C::Error>> {
-        let mut url = self
-            .endpoint
-            .url_base()
-            .endpoint_for(client, &self.endpoint.endpoint())?;
-        self.endpoint.parameters().add_to_url(&mut url);
-
-        let req = Request::builder()
-            .method(self.endpoint.method())
-            .uri(query::url_to_http_uri(url));
-        let (req, data) = if let Some((mime, data)) = self.endpoint.body()? {
-            let req = req.header(header::CONTENT_TYPE, mime);
-            (req, data)
-        } else {
-            (req, Vec::new())
-        };
+        let url = query::endpoint_url(&self.endpoint, client)?;
+        let (req, data) = query::endpoint_request(&self.endpoint, url)?;
         let rsp = client.rest(req, data)?;
-        let status = rsp.status();
-        if !status.is_success() {
-            let v = if let Ok(v) = serde_json::from_slice(rsp.body()) {
-                v
-            } else {
-                return Err(ApiError::server_error(status, rsp.body()));
-            };
-            return Err(ApiError::Alpaca {
-                msg: v,
-            });
-        } else if status == http::StatusCode::MOVED_PERMANENTLY {
-            return Err(ApiError::moved_permanently(
-                rsp.headers().get(http::header::LOCATION),
-            ));
-        }
+        query::check_response(&rsp)?;
+
+        Ok(rsp.into_body().as_ref().into())
+    }
+}
+
+#[async_trait]
+impl<E, C> AsyncQuery<Vec<u8>, C> for Raw<E>
+    where
+        E: Endpoint + Sync,
+        C: AsyncClient + Sync,
+{
+    async fn query_async(&self, client: &C) -> Result<Vec<u8>, ApiError<C::Error>> {
+        let url = query::endpoint_url(&self.endpoint, client)?;
+        let (req, data) = query::endpoint_request(&self.endpoint, url)?;
+        let rsp = client.rest_async(req, data).await?;
+        query::check_response(&rsp)?;
 
         Ok(rsp.into_body().as_ref().into())
     }
 }
 
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+    use http::Method;
+    use crate::test_client::MockClient;
+    use super::*;
+
+    struct Account;
+
+    impl Endpoint for Account {
+        fn method(&self) -> Method {
+            Method::GET
+        }
+
+        fn endpoint(&self) -> Cow<'static, str> {
+            "account".into()
+        }
+    }
+
+    #[test]
+    fn raw_returns_the_body() {
+        let client = MockClient::new(200, "not json");
+
+        let data = raw(Account).query(&client).unwrap();
+        assert_eq!(data, b"not json");
+        assert!(client.requests()[0].url.starts_with("https://api.test/v2/account"));
+    }
+
+    #[test]
+    fn raw_reports_error_bodies() {
+        let client = MockClient::new(404, r#"{"code": 40410000, "message": "not found"}"#);
+
+        let err = raw(Account).query(&client).unwrap_err();
+        assert!(err.is_not_found());
+        assert_eq!(err.alpaca_code(), Some(40410000));
+    }
+
+    #[tokio::test]
+    async fn raw_async_returns_the_body() {
+        let client = MockClient::new(200, "not json");
+
+        let data = raw(Account).query_async(&client).await.unwrap();
+        assert_eq!(data, b"not json");
+        assert!(client.requests()[0].url.starts_with("https://api.test/v2/account"));
+    }
+
+    #[tokio::test]
+    async fn raw_async_reports_error_bodies() {
+        let client = MockClient::new(404, r#"{"code": 40410000, "message": "not found"}"#);
+
+        let err = raw(Account).query_async(&client).await.unwrap_err();
+        assert!(err.is_not_found());
+        assert_eq!(err.alpaca_code(), Some(40410000));
+    }
+}
@@ -0,0 +1,274 @@
+use std::time::Duration;
+use chrono::{DateTime, Utc};
+use derive_builder::Builder;
+use http::{header, HeaderMap, Method, StatusCode};
+use rand::Rng;
+
+/// The header Alpaca uses to report when the rate limit window resets (as a UNIX timestamp).
+const RATE_LIMIT_RESET: &str = "x-ratelimit-reset";
+
+/// How a client retries requests which failed with a rate limit (429) or server (5xx) error.
+///
+/// Only idempotent requests are retried by default. Order submissions may be retried by enabling
+/// `retry_orders_with_client_order_id`; Alpaca rejects duplicate client order IDs, so a retried
+/// submission can never place a second order.
+#[derive(Debug, Clone, Builder)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct RetryPolicy {
+    /// The maximum number of attempts, including the first request.
+    #[builder(default = "3")]
+    max_attempts: u32,
+    /// The delay before the first retry.
+    #[builder(default = "Duration::from_millis(500)")]
+    initial_backoff: Duration,
+    /// The maximum delay between two attempts, unless Alpaca asks for a longer one.
+    #[builder(default = "Duration::from_secs(30)")]
+    max_backoff: Duration,
+    /// Also retry `POST` requests whose JSON body carries a `client_order_id`.
+    #[builder(default)]
+    retry_orders_with_client_order_id: bool,
+}
+
+impl RetryPolicy {
+    /// Create a builder for the retry policy.
+    pub fn builder() -> RetryPolicyBuilder {
+        RetryPolicyBuilder::default()
+    }
+
+    /// Whether a request may be sent more than once.
+    pub(crate) fn allows(&self, method: &Method, body: &[u8]) -> bool {
+        if method.is_idempotent() {
+            return true;
+        }
+
+        self.retry_orders_with_client_order_id
+            && method == Method::POST
+            && serde_json::from_slice::<serde_json::Value>(body)
+                .ok()
+                .and_then(|body| body.get("client_order_id").map(|id| !id.is_null()))
+                .unwrap_or(false)
+    }
+
+    /// The delay before retrying a request, if it should be retried at all.
+    ///
+    /// `attempt` is the number of attempts made so far, starting at 1.
+    pub(crate) fn retry_delay(
+        &self,
+        attempt: u32,
+        status: StatusCode,
+        headers: &HeaderMap,
+    ) -> Option<Duration> {
+        let retryable = status == StatusCode::TOO_MANY_REQUESTS || status.is_server_error();
+        if !retryable || attempt >= self.max_attempts {
+            return None;
+        }
+
+        let requested = retry_after(headers).or_else(|| {
+            if status == StatusCode::TOO_MANY_REQUESTS {
+                rate_limit_reset(headers)
+            } else {
+                None
+            }
+        });
+
+        Some(requested.unwrap_or_else(|| self.backoff(attempt)))
+    }
+
+    /// Exponential backoff with "equal jitter": half of the delay is fixed, half is random.
+    fn backoff(&self, attempt: u32) -> Duration {
+        let exponent = attempt.saturating_sub(1).min(31);
+        let backoff = self
+            .initial_backoff
+            .saturating_mul(1 << exponent)
+            .min(self.max_backoff);
+        let half = backoff / 2;
+        half + half.mul_f64(rand::thread_rng().gen::<f64>())
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        RetryPolicy::builder()
+            .build()
+            .expect("the default retry policy is valid")
+    }
+}
+
+impl RetryPolicyBuilder {
+    fn validate(&self) -> Result<(), String> {
+        if self.max_attempts == Some(0) {
+            return Err("`max_attempts` must be at least 1".into());
+        }
+
+        Ok(())
+    }
+}
+
+/// Parse a `Retry-After` header given either in seconds or as an HTTP date.
+fn retry_after(headers: &HeaderMap) -> Option<Duration> {
+    let value = headers.get(header::RETRY_AFTER)?.to_str().ok()?.trim();
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+
+    let at = DateTime::parse_from_rfc2822(value).ok()?;
+    Some(until(at.timestamp()))
+}
+
+/// Parse the `X-RateLimit-Reset` header into the time remaining until the reset.
+fn rate_limit_reset(headers: &HeaderMap) -> Option<Duration> {
+    let reset = headers
+        .get(RATE_LIMIT_RESET)?
+        .to_str()
+        .ok()?
+        .trim()
+        .parse::<i64>()
+        .ok()?;
+    Some(until(reset))
+}
+
+/// The time remaining until a UNIX timestamp, or zero if it has passed.
+fn until(timestamp: i64) -> Duration {
+    let remaining = timestamp - Utc::now().timestamp();
+    Duration::from_secs(remaining.max(0) as u64)
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Duration as ChronoDuration;
+    use http::HeaderValue;
+    use super::*;
+
+    fn policy() -> RetryPolicy {
+        RetryPolicy::builder()
+            .max_attempts(4)
+            .initial_backoff(Duration::from_secs(1))
+            .max_backoff(Duration::from_secs(5))
+            .build()
+            .unwrap()
+    }
+
+    fn headers(name: &'static str, value: &str) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert(name, HeaderValue::from_str(value).unwrap());
+        headers
+    }
+
+    #[test]
+    fn max_attempts_must_be_positive() {
+        assert!(RetryPolicy::builder().max_attempts(0).build().is_err());
+    }
+
+    #[test]
+    fn retries_idempotent_requests() {
+        let policy = RetryPolicy::default();
+
+        assert!(policy.allows(&Method::GET, b""));
+        assert!(policy.allows(&Method::DELETE, b""));
+        assert!(!policy.allows(&Method::POST, br#"{"client_order_id": "id"}"#));
+        assert!(!policy.allows(&Method::PATCH, b"{}"));
+    }
+
+    #[test]
+    fn retries_orders_with_client_order_id() {
+        let policy = RetryPolicy::builder()
+            .retry_orders_with_client_order_id(true)
+            .build()
+            .unwrap();
+
+        assert!(policy.allows(&Method::POST, br#"{"client_order_id": "id"}"#));
+        assert!(!policy.allows(&Method::POST, br#"{"client_order_id": null}"#));
+        assert!(!policy.allows(&Method::POST, br#"{"symbol": "AAPL"}"#));
+        assert!(!policy.allows(&Method::POST, b"not json"));
+        assert!(!policy.allows(&Method::PATCH, br#"{"client_order_id": "id"}"#));
+    }
+
+    #[test]
+    fn retries_only_rate_limit_and_server_errors() {
+        let policy = policy();
+        let headers = HeaderMap::new();
+
+        assert!(policy.retry_delay(1, StatusCode::TOO_MANY_REQUESTS, &headers).is_some());
+        assert!(policy.retry_delay(1, StatusCode::BAD_GATEWAY, &headers).is_some());
+        assert!(policy.retry_delay(1, StatusCode::BAD_REQUEST, &headers).is_none());
+        assert!(policy.retry_delay(1, StatusCode::NOT_FOUND, &headers).is_none());
+    }
+
+    #[test]
+    fn stops_after_max_attempts() {
+        let policy = policy();
+        let headers = HeaderMap::new();
+
+        assert!(policy.retry_delay(3, StatusCode::SERVICE_UNAVAILABLE, &headers).is_some());
+        assert!(policy.retry_delay(4, StatusCode::SERVICE_UNAVAILABLE, &headers).is_none());
+    }
+
+    #[test]
+    fn backoff_grows_with_equal_jitter() {
+        let policy = policy();
+
+        for (attempt, full) in [(1, 1), (2, 2), (3, 4), (4, 5), (40, 5)] {
+            let full = Duration::from_secs(full);
+            let backoff = policy.backoff(attempt);
+            assert!(backoff >= full / 2 && backoff <= full, "{:?} for {}", backoff, attempt);
+        }
+    }
+
+    #[test]
+    fn honors_retry_after_seconds() {
+        let delay = policy().retry_delay(
+            1,
+            StatusCode::SERVICE_UNAVAILABLE,
+            &headers("retry-after", " 42 "),
+        );
+
+        assert_eq!(delay, Some(Duration::from_secs(42)));
+    }
+
+    #[test]
+    fn honors_retry_after_dates() {
+        let at = (Utc::now() + ChronoDuration::seconds(60)).to_rfc2822();
+        let delay = policy()
+            .retry_delay(1, StatusCode::TOO_MANY_REQUESTS, &headers("retry-after", &at))
+            .unwrap();
+
+        assert!(delay > Duration::from_secs(55) && delay <= Duration::from_secs(60));
+    }
+
+    #[test]
+    fn past_retry_after_dates_retry_immediately() {
+        let delay = policy().retry_delay(
+            1,
+            StatusCode::TOO_MANY_REQUESTS,
+            &headers("retry-after", "Wed, 21 Oct 2015 07:28:00 GMT"),
+        );
+
+        assert_eq!(delay, Some(Duration::ZERO));
+    }
+
+    #[test]
+    fn invalid_retry_after_falls_back_to_backoff() {
+        let delay = policy()
+            .retry_delay(1, StatusCode::SERVICE_UNAVAILABLE, &headers("retry-after", "soon"))
+            .unwrap();
+
+        assert!(delay <= Duration::from_secs(1));
+    }
+
+    #[test]
+    fn honors_rate_limit_reset_for_rate_limits() {
+        let reset = (Utc::now().timestamp() + 30).to_string();
+        let headers = headers(RATE_LIMIT_RESET, &reset);
+        let policy = policy();
+
+        let delay = policy
+            .retry_delay(1, StatusCode::TOO_MANY_REQUESTS, &headers)
+            .unwrap();
+        assert!(delay > Duration::from_secs(25) && delay <= Duration::from_secs(30));
+
+        let delay = policy
+            .retry_delay(1, StatusCode::SERVICE_UNAVAILABLE, &headers)
+            .unwrap();
+        assert!(delay <= Duration::from_secs(1));
+    }
+}
@@ -0,0 +1,1189 @@
+use std::borrow::Cow;
+use chrono::{DateTime, NaiveDate, Utc};
+use derive_builder::Builder;
+use http::Method;
+use crate::common::{CommaSeparatedList, SortOrder};
+use crate::endpoint::{Endpoint, UrlBase};
+use crate::market_data::{validate_bars, validate_limit, validate_symbol, TimeFrame};
+use crate::paged::Pageable;
+use crate::params::{ParamValue, QueryParams};
+
+/// The source of stock market data.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum StockFeed {
+    /// All US exchanges (requires a subscription).
+    Sip,
+    /// The Investors Exchange only.
+    Iex,
+    /// Over-the-counter exchanges.
+    Otc,
+    /// Blue Ocean ATS for overnight trading.
+    Boats,
+    /// Alpaca's derived overnight feed.
+    Overnight,
+    /// The SIP feed delayed by 15 minutes.
+    DelayedSip,
+}
+
+impl StockFeed {
+    /// The feed as a query parameter.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            StockFeed::Sip => "sip",
+            StockFeed::Iex => "iex",
+            StockFeed::Otc => "otc",
+            StockFeed::Boats => "boats",
+            StockFeed::Overnight => "overnight",
+            StockFeed::DelayedSip => "delayed_sip",
+        }
+    }
+}
+
+impl ParamValue<'static> for StockFeed {
+    fn as_value(&self) -> Cow<'static, str> {
+        self.as_str().into()
+    }
+}
+
+/// Corporate action adjustments applied to bars.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Adjustment {
+    /// No adjustment.
+    Raw,
+    /// Adjust for splits.
+    Split,
+    /// Adjust for dividends.
+    Dividend,
+    /// Adjust for splits and dividends.
+    All,
+}
+
+impl Adjustment {
+    /// The adjustment as a query parameter.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Adjustment::Raw => "raw",
+            Adjustment::Split => "split",
+            Adjustment::Dividend => "dividend",
+            Adjustment::All => "all",
+        }
+    }
+}
+
+impl ParamValue<'static> for Adjustment {
+    fn as_value(&self) -> Cow<'static, str> {
+        self.as_str().into()
+    }
+}
+
+/// Query for historical bars of multiple stocks.
+///
+/// Returns `models::Bars`; when paged, each item is a `(symbol, models::Bar)` pair.
+#[derive(Debug, Clone, Builder)]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
+pub struct StockBars {
+    /// The symbols to query.
+    #[builder(setter(name = "_symbols"), private)]
+    symbols: CommaSeparatedList<String>,
+    /// The time frame of each bar.
+    timeframe: TimeFrame,
+    /// The inclusive start of the interval.
+    #[builder(default)]
+    start: Option<DateTime<Utc>>,
+    /// The inclusive end of the interval.
+    #[builder(default)]
+    end: Option<DateTime<Utc>>,
+    /// The maximum number of bars per page across all symbols.
+    #[builder(default)]
+    limit: Option<u64>,
+    /// The corporate action adjustment to apply.
+    #[builder(default)]
+    adjustment: Option<Adjustment>,
+    /// Query symbols as they were named on this date.
+    #[builder(default)]
+    asof: Option<NaiveDate>,
+    /// The source of the data.
+    #[builder(default)]
+    feed: Option<StockFeed>,
+    /// The currency of prices (ISO 4217), USD by default.
+    #[builder(setter(into), default)]
+    currency: Option<String>,
+    /// The page to start from.
+    #[builder(setter(into), default)]
+    page_token: Option<String>,
+    /// The order of the bars by timestamp.
+    #[builder(default)]
+    sort: Option<SortOrder>,
+}
+
+impl StockBars {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> StockBarsBuilder {
+        StockBarsBuilder::default()
+    }
+}
+
+symbol_setters!(StockBarsBuilder);
+
+impl StockBarsBuilder {
+    fn validate(&self) -> Result<(), String> {
+        self.validate_symbols()?;
+
+        validate_bars(self.timeframe, self.limit.flatten())
+    }
+}
+
+impl Endpoint for StockBars {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "stocks/bars".into()
+    }
+
+    fn url_base(&self) -> UrlBase {
+        UrlBase::DataV2
+    }
+
+    fn parameters(&self) -> QueryParams<'_> {
+        let mut params = QueryParams::default();
+
+        params
+            .push("symbols", &self.symbols)
+            .push("timeframe", self.timeframe)
+            .push_opt("start", self.start)
+            .push_opt("end", self.end)
+            .push_opt("limit", self.limit)
+            .push_opt("adjustment", self.adjustment)
+            .push_opt("asof", self.asof)
+            .push_opt("feed", self.feed)
+            .push_opt("currency", self.currency.as_ref())
+            .push_opt("page_token", self.page_token.as_ref())
+            .push_opt("sort", self.sort);
+
+        params
+    }
+}
+
+impl Pageable for StockBars {
+    fn items_field(&self) -> &'static str {
+        "bars"
+    }
+}
+
+/// Query for historical bars of a single stock.
+///
+/// Returns `models::SymbolBars`; when paged, each item is a `models::Bar`.
+#[derive(Debug, Clone, Builder)]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
+pub struct SingleStockBars {
+    /// The symbol to query.
+    #[builder(setter(into))]
+    symbol: String,
+    /// The time frame of each bar.
+    timeframe: TimeFrame,
+    /// The inclusive start of the interval.
+    #[builder(default)]
+    start: Option<DateTime<Utc>>,
+    /// The inclusive end of the interval.
+    #[builder(default)]
+    end: Option<DateTime<Utc>>,
+    /// The maximum number of bars per page.
+    #[builder(default)]
+    limit: Option<u64>,
+    /// The corporate action adjustment to apply.
+    #[builder(default)]
+    adjustment: Option<Adjustment>,
+    /// Query the symbol as it was named on this date.
+    #[builder(default)]
+    asof: Option<NaiveDate>,
+    /// The source of the data.
+    #[builder(default)]
+    feed: Option<StockFeed>,
+    /// The currency of prices (ISO 4217), USD by default.
+    #[builder(setter(into), default)]
+    currency: Option<String>,
+    /// The page to start from.
+    #[builder(setter(into), default)]
+    page_token: Option<String>,
+    /// The order of the bars by timestamp.
+    #[builder(default)]
+    sort: Option<SortOrder>,
+}
+
+impl SingleStockBars {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> SingleStockBarsBuilder {
+        SingleStockBarsBuilder::default()
+    }
+}
+
+impl SingleStockBarsBuilder {
+    fn validate(&self) -> Result<(), String> {
+        validate_symbol(self.symbol.as_deref())?;
+        validate_bars(self.timeframe, self.limit.flatten())
+    }
+}
+
+impl Endpoint for SingleStockBars {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("stocks/{}/bars", self.symbol).into()
+    }
+
+    fn url_base(&self) -> UrlBase {
+        UrlBase::DataV2
+    }
+
+    fn parameters(&self) -> QueryParams<'_> {
+        let mut params = QueryParams::default();
+
+        params
+            .push("timeframe", self.timeframe)
+            .push_opt("start", self.start)
+            .push_opt("end", self.end)
+            .push_opt("limit", self.limit)
+            .push_opt("adjustment", self.adjustment)
+            .push_opt("asof", self.asof)
+            .push_opt("feed", self.feed)
+            .push_opt("currency", self.currency.as_ref())
+            .push_opt("page_token", self.page_token.as_ref())
+            .push_opt("sort", self.sort);
+
+        params
+    }
+}
+
+impl Pageable for SingleStockBars {
+    fn items_field(&self) -> &'static str {
+        "bars"
+    }
+}
+
+/// Query for historical trades of multiple stocks.
+///
+/// Returns `models::Trades`; when paged, each item is a `(symbol, models::Trade)` pair.
+#[derive(Debug, Clone, Builder)]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
+pub struct StockTrades {
+    /// The symbols to query.
+    #[builder(setter(name = "_symbols"), private)]
+    symbols: CommaSeparatedList<String>,
+    /// The inclusive start of the interval.
+    #[builder(default)]
+    start: Option<DateTime<Utc>>,
+    /// The inclusive end of the interval.
+    #[builder(default)]
+    end: Option<DateTime<Utc>>,
+    /// The maximum number of trades per page across all symbols.
+    #[builder(default)]
+    limit: Option<u64>,
+    /// Query symbols as they were named on this date.
+    #[builder(default)]
+    asof: Option<NaiveDate>,
+    /// The source of the data.
+    #[builder(default)]
+    feed: Option<StockFeed>,
+    /// The currency of prices (ISO 4217), USD by default.
+    #[builder(setter(into), default)]
+    currency: Option<String>,
+    /// The page to start from.
+    #[builder(setter(into), default)]
+    page_token: Option<String>,
+    /// The order of the trades by timestamp.
+    #[builder(default)]
+    sort: Option<SortOrder>,
+}
+
+impl StockTrades {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> StockTradesBuilder {
+        StockTradesBuilder::default()
+    }
+}
+
+symbol_setters!(StockTradesBuilder);
+
+impl StockTradesBuilder {
+    fn validate(&self) -> Result<(), String> {
+        self.validate_symbols()?;
+
+        validate_limit(self.limit.flatten())
+    }
+}
+
+impl Endpoint for StockTrades {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "stocks/trades".into()
+    }
+
+    fn url_base(&self) -> UrlBase {
+        UrlBase::DataV2
+    }
+
+    fn parameters(&self) -> QueryParams<'_> {
+        let mut params = QueryParams::default();
+
+        params
+            .push("symbols", &self.symbols)
+            .push_opt("start", self.start)
+            .push_opt("end", self.end)
+            .push_opt("limit", self.limit)
+            .push_opt("asof", self.asof)
+            .push_opt("feed", self.feed)
+            .push_opt("currency", self.currency.as_ref())
+            .push_opt("page_token", self.page_token.as_ref())
+            .push_opt("sort", self.sort);
+
+        params
+    }
+}
+
+impl Pageable for StockTrades {
+    fn items_field(&self) -> &'static str {
+        "trades"
+    }
+}
+
+/// Query for historical trades of a single stock.
+///
+/// Returns `models::SymbolTrades`; when paged, each item is a `models::Trade`.
+#[derive(Debug, Clone, Builder)]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
+pub struct SingleStockTrades {
+    /// The symbol to query.
+    #[builder(setter(into))]
+    symbol: String,
+    /// The inclusive start of the interval.
+    #[builder(default)]
+    start: Option<DateTime<Utc>>,
+    /// The inclusive end of the interval.
+    #[builder(default)]
+    end: Option<DateTime<Utc>>,
+    /// The maximum number of trades per page.
+    #[builder(default)]
+    limit: Option<u64>,
+    /// Query the symbol as it was named on this date.
+    #[builder(default)]
+    asof: Option<NaiveDate>,
+    /// The source of the data.
+    #[builder(default)]
+    feed: Option<StockFeed>,
+    /// The currency of prices (ISO 4217), USD by default.
+    #[builder(setter(into), default)]
+    currency: Option<String>,
+    /// The page to start from.
+    #[builder(setter(into), default)]
+    page_token: Option<String>,
+    /// The order of the trades by timestamp.
+    #[builder(default)]
+    sort: Option<SortOrder>,
+}
+
+impl SingleStockTrades {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> SingleStockTradesBuilder {
+        SingleStockTradesBuilder::default()
+    }
+}
+
+impl SingleStockTradesBuilder {
+    fn validate(&self) -> Result<(), String> {
+        validate_symbol(self.symbol.as_deref())?;
+        validate_limit(self.limit.flatten())
+    }
+}
+
+impl Endpoint for SingleStockTrades {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("stocks/{}/trades", self.symbol).into()
+    }
+
+    fn url_base(&self) -> UrlBase {
+        UrlBase::DataV2
+    }
+
+    fn parameters(&self) -> QueryParams<'_> {
+        let mut params = QueryParams::default();
+
+        params
+            .push_opt("start", self.start)
+            .push_opt("end", self.end)
+            .push_opt("limit", self.limit)
+            .push_opt("asof", self.asof)
+            .push_opt("feed", self.feed)
+            .push_opt("currency", self.currency.as_ref())
+            .push_opt("page_token", self.page_token.as_ref())
+            .push_opt("sort", self.sort);
+
+        params
+    }
+}
+
+impl Pageable for SingleStockTrades {
+    fn items_field(&self) -> &'static str {
+        "trades"
+    }
+}
+
+/// Query for historical quotes of multiple stocks.
+///
+/// Returns `models::Quotes`; when paged, each item is a `(symbol, models::Quote)` pair.
+#[derive(Debug, Clone, Builder)]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
+pub struct StockQuotes {
+    /// The symbols to query.
+    #[builder(setter(name = "_symbols"), private)]
+    symbols: CommaSeparatedList<String>,
+    /// The inclusive start of the interval.
+    #[builder(default)]
+    start: Option<DateTime<Utc>>,
+    /// The inclusive end of the interval.
+    #[builder(default)]
+    end: Option<DateTime<Utc>>,
+    /// The maximum number of quotes per page across all symbols.
+    #[builder(default)]
+    limit: Option<u64>,
+    /// Query symbols as they were named on this date.
+    #[builder(default)]
+    asof: Option<NaiveDate>,
+    /// The source of the data.
+    #[builder(default)]
+    feed: Option<StockFeed>,
+    /// The currency of prices (ISO 4217), USD by default.
+    #[builder(setter(into), default)]
+    currency: Option<String>,
+    /// The page to start from.
+    #[builder(setter(into), default)]
+    page_token: Option<String>,
+    /// The order of the quotes by timestamp.
+    #[builder(default)]
+    sort: Option<SortOrder>,
+}
+
+impl StockQuotes {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> StockQuotesBuilder {
+        StockQuotesBuilder::default()
+    }
+}
+
+symbol_setters!(StockQuotesBuilder);
+
+impl StockQuotesBuilder {
+    fn validate(&self) -> Result<(), String> {
+        self.validate_symbols()?;
+
+        validate_limit(self.limit.flatten())
+    }
+}
+
+impl Endpoint for StockQuotes {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "stocks/quotes".into()
+    }
+
+    fn url_base(&self) -> UrlBase {
+        UrlBase::DataV2
+    }
+
+    fn parameters(&self) -> QueryParams<'_> {
+        let mut params = QueryParams::default();
+
+        params
+            .push("symbols", &self.symbols)
+            .push_opt("start", self.start)
+            .push_opt("end", self.end)
+            .push_opt("limit", self.limit)
+            .push_opt("asof", self.asof)
+            .push_opt("feed", self.feed)
+            .push_opt("currency", self.currency.as_ref())
+            .push_opt("page_token", self.page_token.as_ref())
+            .push_opt("sort", self.sort);
+
+        params
+    }
+}
+
+impl Pageable for StockQuotes {
+    fn items_field(&self) -> &'static str {
+        "quotes"
+    }
+}
+
+/// Query for historical quotes of a single stock.
+///
+/// Returns `models::SymbolQuotes`; when paged, each item is a `models::Quote`.
+#[derive(Debug, Clone, Builder)]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
+pub struct SingleStockQuotes {
+    /// The symbol to query.
+    #[builder(setter(into))]
+    symbol: String,
+    /// The inclusive start of the interval.
+    #[builder(default)]
+    start: Option<DateTime<Utc>>,
+    /// The inclusive end of the interval.
+    #[builder(default)]
+    end: Option<DateTime<Utc>>,
+    /// The maximum number of quotes per page.
+    #[builder(default)]
+    limit: Option<u64>,
+    /// Query the symbol as it was named on this date.
+    #[builder(default)]
+    asof: Option<NaiveDate>,
+    /// The source of the data.
+    #[builder(default)]
+    feed: Option<StockFeed>,
+    /// The currency of prices (ISO 4217), USD by default.
+    #[builder(setter(into), default)]
+    currency: Option<String>,
+    /// The page to start from.
+    #[builder(setter(into), default)]
+    page_token: Option<String>,
+    /// The order of the quotes by timestamp.
+    #[builder(default)]
+    sort: Option<SortOrder>,
+}
+
+impl SingleStockQuotes {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> SingleStockQuotesBuilder {
+        SingleStockQuotesBuilder::default()
+    }
+}
+
+impl SingleStockQuotesBuilder {
+    fn validate(&self) -> Result<(), String> {
+        validate_symbol(self.symbol.as_deref())?;
+        validate_limit(self.limit.flatten())
+    }
+}
+
+impl Endpoint for SingleStockQuotes {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("stocks/{}/quotes", self.symbol).into()
+    }
+
+    fn url_base(&self) -> UrlBase {
+        UrlBase::DataV2
+    }
+
+    fn parameters(&self) -> QueryParams<'_> {
+        let mut params = QueryParams::default();
+
+        params
+            .push_opt("start", self.start)
+            .push_opt("end", self.end)
+            .push_opt("limit", self.limit)
+            .push_opt("asof", self.asof)
+            .push_opt("feed", self.feed)
+            .push_opt("currency", self.currency.as_ref())
+            .push_opt("page_token", self.page_token.as_ref())
+            .push_opt("sort", self.sort);
+
+        params
+    }
+}
+
+impl Pageable for SingleStockQuotes {
+    fn items_field(&self) -> &'static str {
+        "quotes"
+    }
+}
+
+/// Query for the latest trade of multiple stocks.
+///
+/// Returns `models::LatestTrades`.
+#[derive(Debug, Clone, Builder)]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate_symbols"))]
+pub struct LatestStockTrades {
+    /// The symbols to query.
+    #[builder(setter(name = "_symbols"), private)]
+    symbols: CommaSeparatedList<String>,
+    /// The source of the data.
+    #[builder(default)]
+    feed: Option<StockFeed>,
+    /// The currency of prices (ISO 4217), USD by default.
+    #[builder(setter(into), default)]
+    currency: Option<String>,
+}
+
+impl LatestStockTrades {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> LatestStockTradesBuilder {
+        LatestStockTradesBuilder::default()
+    }
+}
+
+symbol_setters!(LatestStockTradesBuilder);
+
+impl Endpoint for LatestStockTrades {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "stocks/trades/latest".into()
+    }
+
+    fn url_base(&self) -> UrlBase {
+        UrlBase::DataV2
+    }
+
+    fn parameters(&self) -> QueryParams<'_> {
+        let mut params = QueryParams::default();
+
+        params
+            .push("symbols", &self.symbols)
+            .push_opt("feed", self.feed)
+            .push_opt("currency", self.currency.as_ref());
+
+        params
+    }
+}
+
+/// Query for the latest trade of a single stock.
+///
+/// Returns `models::LatestTrade`.
+#[derive(Debug, Clone, Builder)]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
+pub struct LatestStockTrade {
+    /// The symbol to query.
+    #[builder(setter(into))]
+    symbol: String,
+    /// The source of the data.
+    #[builder(default)]
+    feed: Option<StockFeed>,
+    /// The currency of prices (ISO 4217), USD by default.
+    #[builder(setter(into), default)]
+    currency: Option<String>,
+}
+
+impl LatestStockTrade {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> LatestStockTradeBuilder {
+        LatestStockTradeBuilder::default()
+    }
+}
+
+impl LatestStockTradeBuilder {
+    fn validate(&self) -> Result<(), String> {
+        validate_symbol(self.symbol.as_deref())
+    }
+}
+
+impl Endpoint for LatestStockTrade {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("stocks/{}/trades/latest", self.symbol).into()
+    }
+
+    fn url_base(&self) -> UrlBase {
+        UrlBase::DataV2
+    }
+
+    fn parameters(&self) -> QueryParams<'_> {
+        let mut params = QueryParams::default();
+
+        params
+            .push_opt("feed", self.feed)
+            .push_opt("currency", self.currency.as_ref());
+
+        params
+    }
+}
+
+/// Query for the latest quote of multiple stocks.
+///
+/// Returns `models::LatestQuotes`.
+#[derive(Debug, Clone, Builder)]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate_symbols"))]
+pub struct LatestStockQuotes {
+    /// The symbols to query.
+    #[builder(setter(name = "_symbols"), private)]
+    symbols: CommaSeparatedList<String>,
+    /// The source of the data.
+    #[builder(default)]
+    feed: Option<StockFeed>,
+    /// The currency of prices (ISO 4217), USD by default.
+    #[builder(setter(into), default)]
+    currency: Option<String>,
+}
+
+impl LatestStockQuotes {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> LatestStockQuotesBuilder {
+        LatestStockQuotesBuilder::default()
+    }
+}
+
+symbol_setters!(LatestStockQuotesBuilder);
+
+impl Endpoint for LatestStockQuotes {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "stocks/quotes/latest".into()
+    }
+
+    fn url_base(&self) -> UrlBase {
+        UrlBase::DataV2
+    }
+
+    fn parameters(&self) -> QueryParams<'_> {
+        let mut params = QueryParams::default();
+
+        params
+            .push("symbols", &self.symbols)
+            .push_opt("feed", self.feed)
+            .push_opt("currency", self.currency.as_ref());
+
+        params
+    }
+}
+
+/// Query for the latest quote of a single stock.
+///
+/// Returns `models::LatestQuote`.
+#[derive(Debug, Clone, Builder)]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
+pub struct LatestStockQuote {
+    /// The symbol to query.
+    #[builder(setter(into))]
+    symbol: String,
+    /// The source of the data.
+    #[builder(default)]
+    feed: Option<StockFeed>,
+    /// The currency of prices (ISO 4217), USD by default.
+    #[builder(setter(into), default)]
+    currency: Option<String>,
+}
+
+impl LatestStockQuote {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> LatestStockQuoteBuilder {
+        LatestStockQuoteBuilder::default()
+    }
+}
+
+impl LatestStockQuoteBuilder {
+    fn validate(&self) -> Result<(), String> {
+        validate_symbol(self.symbol.as_deref())
+    }
+}
+
+impl Endpoint for LatestStockQuote {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("stocks/{}/quotes/latest", self.symbol).into()
+    }
+
+    fn url_base(&self) -> UrlBase {
+        UrlBase::DataV2
+    }
+
+    fn parameters(&self) -> QueryParams<'_> {
+        let mut params = QueryParams::default();
+
+        params
+            .push_opt("feed", self.feed)
+            .push_opt("currency", self.currency.as_ref());
+
+        params
+    }
+}
+
+/// Query for the latest minute bar of multiple stocks.
+///
+/// Returns `models::LatestBars`.
+#[derive(Debug, Clone, Builder)]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate_symbols"))]
+pub struct LatestStockBars {
+    /// The symbols to query.
+    #[builder(setter(name = "_symbols"), private)]
+    symbols: CommaSeparatedList<String>,
+    /// The source of the data.
+    #[builder(default)]
+    feed: Option<StockFeed>,
+    /// The currency of prices (ISO 4217), USD by default.
+    #[builder(setter(into), default)]
+    currency: Option<String>,
+}
+
+impl LatestStockBars {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> LatestStockBarsBuilder {
+        LatestStockBarsBuilder::default()
+    }
+}
+
+symbol_setters!(LatestStockBarsBuilder);
+
+impl Endpoint for LatestStockBars {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "stocks/bars/latest".into()
+    }
+
+    fn url_base(&self) -> UrlBase {
+        UrlBase::DataV2
+    }
+
+    fn parameters(&self) -> QueryParams<'_> {
+        let mut params = QueryParams::default();
+
+        params
+            .push("symbols", &self.symbols)
+            .push_opt("feed", self.feed)
+            .push_opt("currency", self.currency.as_ref());
+
+        params
+    }
+}
+
+/// Query for the latest minute bar of a single stock.
+///
+/// Returns `models::LatestBar`.
+#[derive(Debug, Clone, Builder)]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
+pub struct LatestStockBar {
+    /// The symbol to query.
+    #[builder(setter(into))]
+    symbol: String,
+    /// The source of the data.
+    #[builder(default)]
+    feed: Option<StockFeed>,
+    /// The currency of prices (ISO 4217), USD by default.
+    #[builder(setter(into), default)]
+    currency: Option<String>,
+}
+
+impl LatestStockBar {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> LatestStockBarBuilder {
+        LatestStockBarBuilder::default()
+    }
+}
+
+impl LatestStockBarBuilder {
+    fn validate(&self) -> Result<(), String> {
+        validate_symbol(self.symbol.as_deref())
+    }
+}
+
+impl Endpoint for LatestStockBar {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("stocks/{}/bars/latest", self.symbol).into()
+    }
+
+    fn url_base(&self) -> UrlBase {
+        UrlBase::DataV2
+    }
+
+    fn parameters(&self) -> QueryParams<'_> {
+        let mut params = QueryParams::default();
+
+        params
+            .push_opt("feed", self.feed)
+            .push_opt("currency", self.currency.as_ref());
+
+        params
+    }
+}
+
+/// Query for snapshots of multiple stocks.
+///
+/// Returns a map from symbol to `models::Snapshot`, e.g. a
+/// `BTreeMap<String, models::Snapshot>`.
+#[derive(Debug, Clone, Builder)]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate_symbols"))]
+pub struct StockSnapshots {
+    /// The symbols to query.
+    #[builder(setter(name = "_symbols"), private)]
+    symbols: CommaSeparatedList<String>,
+    /// The source of the data.
+    #[builder(default)]
+    feed: Option<StockFeed>,
+    /// The currency of prices (ISO 4217), USD by default.
+    #[builder(setter(into), default)]
+    currency: Option<String>,
+}
+
+impl StockSnapshots {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> StockSnapshotsBuilder {
+        StockSnapshotsBuilder::default()
+    }
+}
+
+symbol_setters!(StockSnapshotsBuilder);
+
+impl Endpoint for StockSnapshots {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "stocks/snapshots".into()
+    }
+
+    fn url_base(&self) -> UrlBase {
+        UrlBase::DataV2
+    }
+
+    fn parameters(&self) -> QueryParams<'_> {
+        let mut params = QueryParams::default();
+
+        params
+            .push("symbols", &self.symbols)
+            .push_opt("feed", self.feed)
+            .push_opt("currency", self.currency.as_ref());
+
+        params
+    }
+}
+
+/// Query for a snapshot of a single stock.
+///
+/// Returns `models::Snapshot`.
+#[derive(Debug, Clone, Builder)]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
+pub struct StockSnapshot {
+    /// The symbol to query.
+    #[builder(setter(into))]
+    symbol: String,
+    /// The source of the data.
+    #[builder(default)]
+    feed: Option<StockFeed>,
+    /// The currency of prices (ISO 4217), USD by default.
+    #[builder(setter(into), default)]
+    currency: Option<String>,
+}
+
+impl StockSnapshot {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> StockSnapshotBuilder {
+        StockSnapshotBuilder::default()
+    }
+}
+
+impl StockSnapshotBuilder {
+    fn validate(&self) -> Result<(), String> {
+        validate_symbol(self.symbol.as_deref())
+    }
+}
+
+impl Endpoint for StockSnapshot {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("stocks/{}/snapshot", self.symbol).into()
+    }
+
+    fn url_base(&self) -> UrlBase {
+        UrlBase::DataV2
+    }
+
+    fn parameters(&self) -> QueryParams<'_> {
+        let mut params = QueryParams::default();
+
+        params
+            .push_opt("feed", self.feed)
+            .push_opt("currency", self.currency.as_ref());
+
+        params
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+    use crate::market_data::TimeFrameUnit;
+    use super::*;
+
+    #[test]
+    fn stock_bars_require_a_symbol() {
+        let mut builder = StockBars::builder();
+        builder.timeframe(TimeFrame::DAY);
+        assert!(builder.build().is_err());
+
+        builder.symbol("AAPL");
+        builder.build().unwrap();
+    }
+
+    #[test]
+    fn stock_bars_reject_unsupported_time_frames() {
+        let mut builder = StockBars::builder();
+        builder
+            .symbol("AAPL")
+            .timeframe(TimeFrame::new(90, TimeFrameUnit::Minute));
+        assert!(builder.build().is_err());
+    }
+
+    #[test]
+    fn stock_bars_parameters() {
+        let endpoint = StockBars::builder()
+            .symbols(["AAPL", "MSFT"].into_iter())
+            .timeframe(TimeFrame::new(15, TimeFrameUnit::Minute))
+            .start(Utc.with_ymd_and_hms(2024, 1, 2, 14, 30, 0).unwrap())
+            .adjustment(Adjustment::Split)
+            .feed(StockFeed::Iex)
+            .limit(100)
+            .build()
+            .unwrap();
+
+        assert_eq!(endpoint.endpoint(), "stocks/bars");
+        assert_eq!(
+            endpoint.parameters().to_query_string(),
+            "symbols=AAPL%2CMSFT&timeframe=15Min&start=2024-01-02T14%3A30%3A00Z&limit=100\
+             &adjustment=split&feed=iex",
+        );
+    }
+
+    #[test]
+    fn single_stock_bars_reject_an_empty_symbol() {
+        let mut builder = SingleStockBars::builder();
+        builder.symbol("").timeframe(TimeFrame::DAY);
+        assert!(builder.build().is_err());
+    }
+
+    #[test]
+    fn single_stock_bars_limit_page_size() {
+        let mut builder = SingleStockBars::builder();
+        builder.symbol("AAPL").timeframe(TimeFrame::DAY).limit(0);
+        assert!(builder.build().is_err());
+
+        builder.limit(10000);
+        let endpoint = builder.build().unwrap();
+        assert_eq!(endpoint.endpoint(), "stocks/AAPL/bars");
+        assert_eq!(endpoint.items_field(), "bars");
+    }
+
+    #[test]
+    fn stock_trades_require_a_symbol() {
+        assert!(StockTrades::builder().build().is_err());
+        assert!(StockQuotes::builder().build().is_err());
+    }
+
+    #[test]
+    fn stock_trades_parameters() {
+        let endpoint = StockTrades::builder()
+            .symbol("AAPL")
+            .end(Utc.with_ymd_and_hms(2024, 1, 2, 21, 0, 0).unwrap())
+            .sort(SortOrder::Descending)
+            .page_token("token")
+            .build()
+            .unwrap();
+
+        assert_eq!(endpoint.items_field(), "trades");
+        assert_eq!(
+            endpoint.parameters().to_query_string(),
+            "symbols=AAPL&end=2024-01-02T21%3A00%3A00Z&page_token=token&sort=desc",
+        );
+    }
+
+    #[test]
+    fn single_stock_trades_reject_an_empty_symbol() {
+        assert!(SingleStockTrades::builder().symbol("").build().is_err());
+        assert!(SingleStockQuotes::builder().symbol("").build().is_err());
+    }
+
+    #[test]
+    fn single_stock_quotes_limit_page_size() {
+        let mut builder = SingleStockQuotes::builder();
+        builder.symbol("AAPL").limit(10001);
+        assert!(builder.build().is_err());
+
+        builder.limit(1);
+        let endpoint = builder.build().unwrap();
+        assert_eq!(endpoint.endpoint(), "stocks/AAPL/quotes");
+        assert_eq!(endpoint.items_field(), "quotes");
+    }
+
+    #[test]
+    fn latest_stock_data_require_a_symbol() {
+        assert!(LatestStockTrades::builder().build().is_err());
+        assert!(LatestStockQuotes::builder().build().is_err());
+        assert!(LatestStockBars::builder().build().is_err());
+        assert!(StockSnapshots::builder().build().is_err());
+    }
+
+    #[test]
+    fn latest_stock_data_parameters() {
+        let endpoint = LatestStockQuotes::builder()
+            .symbols(["AAPL", "TSLA"].into_iter())
+            .feed(StockFeed::DelayedSip)
+            .build()
+            .unwrap();
+
+        assert_eq!(endpoint.endpoint(), "stocks/quotes/latest");
+        assert_eq!(
+            endpoint.parameters().to_query_string(),
+            "symbols=AAPL%2CTSLA&feed=delayed_sip",
+        );
+    }
+
+    #[test]
+    fn single_stock_latest_data_reject_an_empty_symbol() {
+        assert!(LatestStockTrade::builder().symbol("").build().is_err());
+        assert!(LatestStockQuote::builder().symbol("").build().is_err());
+        assert!(LatestStockBar::builder().symbol("").build().is_err());
+        assert!(StockSnapshot::builder().symbol("").build().is_err());
+    }
+
+    #[test]
+    fn single_stock_latest_data_endpoints() {
+        let trade = LatestStockTrade::builder().symbol("AAPL").build().unwrap();
+        let bar = LatestStockBar::builder().symbol("AAPL").build().unwrap();
+        let snapshot = StockSnapshot::builder()
+            .symbol("AAPL")
+            .feed(StockFeed::Iex)
+            .build()
+            .unwrap();
+
+        assert_eq!(trade.endpoint(), "stocks/AAPL/trades/latest");
+        assert_eq!(bar.endpoint(), "stocks/AAPL/bars/latest");
+        assert_eq!(snapshot.endpoint(), "stocks/AAPL/snapshot");
+        assert_eq!(snapshot.parameters().to_query_string(), "feed=iex");
+    }
+}
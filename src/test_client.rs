@@ -0,0 +1,100 @@
+//! A client answering every request with a canned response, for tests of the query plumbing.
+
+use std::sync::Mutex;
+use async_trait::async_trait;
+use bytes::Bytes;
+use http::{header, Method, Response};
+use http::request::Builder as RequestBuilder;
+use thiserror::Error;
+use url::Url;
+use crate::client::{AsyncClient, Client, RestClient};
+use crate::error::ApiError;
+
+#[derive(Debug, Error)]
+#[error("mock client error")]
+pub(crate) struct MockError;
+
+/// A request as seen by the mock client.
+#[derive(Debug, Clone)]
+pub(crate) struct MockRequest {
+    pub(crate) method: Method,
+    pub(crate) url: String,
+    pub(crate) content_type: Option<String>,
+    pub(crate) body: Vec<u8>,
+}
+
+/// A client which answers every request with the same status and body.
+pub(crate) struct MockClient {
+    status: u16,
+    body: Bytes,
+    requests: Mutex<Vec<MockRequest>>,
+}
+
+impl MockClient {
+    pub(crate) fn new<B>(status: u16, body: B) -> Self
+        where
+            B: Into<Bytes>,
+    {
+        MockClient {
+            status,
+            body: body.into(),
+            requests: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// The requests sent to the client so far.
+    pub(crate) fn requests(&self) -> Vec<MockRequest> {
+        self.requests.lock().unwrap().clone()
+    }
+
+    fn respond(&self, request: RequestBuilder, body: Vec<u8>) -> Response<Bytes> {
+        let content_type = request
+            .headers_ref()
+            .and_then(|headers| headers.get(header::CONTENT_TYPE))
+            .map(|value| value.to_str().unwrap().into());
+        self.requests.lock().unwrap().push(MockRequest {
+            method: request.method_ref().unwrap().clone(),
+            url: request.uri_ref().unwrap().to_string(),
+            content_type,
+            body,
+        });
+
+        Response::builder()
+            .status(self.status)
+            .body(self.body.clone())
+            .unwrap()
+    }
+}
+
+impl RestClient for MockClient {
+    type Error = MockError;
+
+    fn rest_endpoint(&self, endpoint: &str) -> Result<Url, ApiError<Self::Error>> {
+        Ok(Url::parse("https://api.test/v2/")?.join(endpoint)?)
+    }
+
+    fn data_endpoint(&self, endpoint: &str) -> Result<Url, ApiError<Self::Error>> {
+        Ok(Url::parse("https://data.test/")?.join(endpoint)?)
+    }
+}
+
+impl Client for MockClient {
+    fn rest(
+        &self,
+        request: RequestBuilder,
+        body: Vec<u8>,
+    ) -> Result<Response<Bytes>, ApiError<Self::Error>> {
+        Ok(self.respond(request, body))
+    }
+}
+
+#[async_trait]
+impl AsyncClient for MockClient {
+    async fn rest_async(
+        &self,
+        request: RequestBuilder,
+        body: Vec<u8>,
+    ) -> Result<Response<Bytes>, ApiError<Self::Error>> {
+        Ok(self.respond(request, body))
+    }
+}
@@ -0,0 +1,480 @@
+use std::borrow::Cow;
+use derive_builder::Builder;
+use http::Method;
+use serde::Serialize;
+use crate::common::{is_set, path_segment};
+use crate::endpoint::Endpoint;
+use crate::error::BodyError;
+use crate::params::QueryParams;
+
+/// How a watchlist is identified.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum WatchlistSelector {
+    /// The ID of the watchlist.
+    Id(String),
+    /// The name of the watchlist.
+    Name(String),
+}
+
+impl WatchlistSelector {
+    /// Select a watchlist by ID.
+    pub fn id<S>(id: S) -> Self
+        where
+            S: Into<String>,
+    {
+        WatchlistSelector::Id(id.into())
+    }
+
+    /// Select a watchlist by name.
+    pub fn name<S>(name: S) -> Self
+        where
+            S: Into<String>,
+    {
+        WatchlistSelector::Name(name.into())
+    }
+
+    /// The path of the watchlist, optionally followed by a sub-path.
+    fn endpoint(&self, sub_path: Option<&str>) -> Cow<'static, str> {
+        let path = match self {
+            WatchlistSelector::Id(id) => format!("watchlists/{}", path_segment(id)),
+            WatchlistSelector::Name(_) => "watchlists:by_name".into(),
+        };
+        match sub_path {
+            Some(sub_path) => format!("{}/{}", path, path_segment(sub_path)).into(),
+            None => path.into(),
+        }
+    }
+
+    /// The query parameters selecting the watchlist.
+    fn parameters(&self) -> QueryParams<'_> {
+        let mut params = QueryParams::default();
+
+        if let WatchlistSelector::Name(name) = self {
+            params.push("name", name);
+        }
+
+        params
+    }
+}
+
+/// Query for all watchlists.
+///
+/// Returns a list of `models::Watchlist` without their assets.
+#[derive(Debug, Clone, Copy, Builder)]
+pub struct Watchlists {}
+
+impl Watchlists {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> WatchlistsBuilder {
+        WatchlistsBuilder::default()
+    }
+}
+
+impl Endpoint for Watchlists {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "watchlists".into()
+    }
+}
+
+/// Create a watchlist.
+///
+/// Returns the created `models::Watchlist`.
+#[derive(Debug, Clone, Builder, Serialize)]
+pub struct CreateWatchlist {
+    /// The name of the watchlist.
+    #[builder(setter(into))]
+    name: String,
+    /// The symbols of the watchlist.
+    #[builder(setter(name = "_symbols"), default, private)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    symbols: Vec<String>,
+}
+
+impl CreateWatchlist {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> CreateWatchlistBuilder {
+        CreateWatchlistBuilder::default()
+    }
+}
+
+impl CreateWatchlistBuilder {
+    /// Add a symbol to the watchlist.
+    pub fn symbol<S>(&mut self, symbol: S) -> &mut Self
+        where
+            S: Into<String>,
+    {
+        self.symbols.get_or_insert_with(Vec::new).push(symbol.into());
+        self
+    }
+
+    /// Add a set of symbols to the watchlist.
+    pub fn symbols<I, S>(&mut self, iter: I) -> &mut Self
+        where
+            I: Iterator<Item = S>,
+            S: Into<String>,
+    {
+        self.symbols
+            .get_or_insert_with(Vec::new)
+            .extend(iter.map(Into::into));
+        self
+    }
+}
+
+impl Endpoint for CreateWatchlist {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "watchlists".into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        Ok(Some(("application/json", serde_json::to_vec(self)?)))
+    }
+}
+
+/// Query for a single watchlist.
+///
+/// Returns a `models::Watchlist`.
+#[derive(Debug, Clone, Builder)]
+pub struct Watchlist {
+    /// The watchlist to query.
+    watchlist: WatchlistSelector,
+}
+
+impl Watchlist {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> WatchlistBuilder {
+        WatchlistBuilder::default()
+    }
+}
+
+impl Endpoint for Watchlist {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        self.watchlist.endpoint(None)
+    }
+
+    fn parameters(&self) -> QueryParams<'_> {
+        self.watchlist.parameters()
+    }
+}
+
+/// Replace the name and/or symbols of a watchlist.
+///
+/// Returns the updated `models::Watchlist`. Symbols replace the current ones; give an empty set
+/// to clear the watchlist.
+#[derive(Debug, Clone, Builder, Serialize)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct UpdateWatchlist {
+    /// The watchlist to update.
+    #[serde(skip)]
+    watchlist: WatchlistSelector,
+    /// The new name of the watchlist.
+    #[builder(setter(into, strip_option), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    /// The new symbols of the watchlist.
+    #[builder(setter(name = "_symbols"), default, private)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    symbols: Option<Vec<String>>,
+}
+
+impl UpdateWatchlist {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> UpdateWatchlistBuilder {
+        UpdateWatchlistBuilder::default()
+    }
+}
+
+impl UpdateWatchlistBuilder {
+    /// Add a symbol to the new symbols of the watchlist.
+    pub fn symbol<S>(&mut self, symbol: S) -> &mut Self
+        where
+            S: Into<String>,
+    {
+        self.symbols
+            .get_or_insert(None)
+            .get_or_insert_with(Vec::new)
+            .push(symbol.into());
+        self
+    }
+
+    /// Add a set of symbols to the new symbols of the watchlist.
+    pub fn symbols<I, S>(&mut self, iter: I) -> &mut Self
+        where
+            I: Iterator<Item = S>,
+            S: Into<String>,
+    {
+        self.symbols
+            .get_or_insert(None)
+            .get_or_insert_with(Vec::new)
+            .extend(iter.map(Into::into));
+        self
+    }
+
+    fn validate(&self) -> Result<(), String> {
+        if !is_set(&self.name) && !is_set(&self.symbols) {
+            return Err("at least one of `name` or `symbols` must be given".into());
+        }
+
+        Ok(())
+    }
+}
+
+impl Endpoint for UpdateWatchlist {
+    fn method(&self) -> Method {
+        Method::PUT
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        self.watchlist.endpoint(None)
+    }
+
+    fn parameters(&self) -> QueryParams<'_> {
+        self.watchlist.parameters()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        Ok(Some(("application/json", serde_json::to_vec(self)?)))
+    }
+}
+
+/// Delete a watchlist.
+///
+/// Alpaca answers with an empty body; use `ignore` to query this endpoint.
+#[derive(Debug, Clone, Builder)]
+pub struct DeleteWatchlist {
+    /// The watchlist to delete.
+    watchlist: WatchlistSelector,
+}
+
+impl DeleteWatchlist {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> DeleteWatchlistBuilder {
+        DeleteWatchlistBuilder::default()
+    }
+}
+
+impl Endpoint for DeleteWatchlist {
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        self.watchlist.endpoint(None)
+    }
+
+    fn parameters(&self) -> QueryParams<'_> {
+        self.watchlist.parameters()
+    }
+}
+
+/// Add a symbol to a watchlist.
+///
+/// Returns the updated `models::Watchlist`.
+#[derive(Debug, Clone, Builder, Serialize)]
+pub struct AddToWatchlist {
+    /// The watchlist to add to.
+    #[serde(skip)]
+    watchlist: WatchlistSelector,
+    /// The symbol to add.
+    #[builder(setter(into))]
+    symbol: String,
+}
+
+impl AddToWatchlist {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> AddToWatchlistBuilder {
+        AddToWatchlistBuilder::default()
+    }
+}
+
+impl Endpoint for AddToWatchlist {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        self.watchlist.endpoint(None)
+    }
+
+    fn parameters(&self) -> QueryParams<'_> {
+        self.watchlist.parameters()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        Ok(Some(("application/json", serde_json::to_vec(self)?)))
+    }
+}
+
+/// Remove a symbol from a watchlist.
+///
+/// Returns the updated `models::Watchlist`.
+#[derive(Debug, Clone, Builder)]
+pub struct RemoveFromWatchlist {
+    /// The watchlist to remove from.
+    watchlist: WatchlistSelector,
+    /// The symbol to remove.
+    #[builder(setter(into))]
+    symbol: String,
+}
+
+impl RemoveFromWatchlist {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> RemoveFromWatchlistBuilder {
+        RemoveFromWatchlistBuilder::default()
+    }
+}
+
+impl Endpoint for RemoveFromWatchlist {
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        self.watchlist.endpoint(Some(&self.symbol))
+    }
+
+    fn parameters(&self) -> QueryParams<'_> {
+        self.watchlist.parameters()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{json, Value};
+    use super::*;
+
+    fn body<E>(endpoint: &E) -> Value
+        where
+            E: Endpoint,
+    {
+        let (content_type, body) = endpoint.body().unwrap().unwrap();
+        assert_eq!(content_type, "application/json");
+        serde_json::from_slice(&body).unwrap()
+    }
+
+    #[test]
+    fn create_watchlist() {
+        let endpoint = CreateWatchlist::builder()
+            .name("tech")
+            .symbol("AAPL")
+            .symbols(["MSFT", "GOOGL"].into_iter())
+            .build()
+            .unwrap();
+
+        assert_eq!(body(&endpoint), json!({"name": "tech", "symbols": ["AAPL", "MSFT", "GOOGL"]}));
+
+        let endpoint = CreateWatchlist::builder().name("empty").build().unwrap();
+        assert_eq!(body(&endpoint), json!({"name": "empty"}));
+    }
+
+    #[test]
+    fn watchlist_by_id() {
+        let endpoint = Watchlist::builder()
+            .watchlist(WatchlistSelector::id("fb306e55-16d3-4118-8c3d-c1615fcd4c03"))
+            .build()
+            .unwrap();
+
+        assert_eq!(endpoint.endpoint(), "watchlists/fb306e55-16d3-4118-8c3d-c1615fcd4c03");
+        assert_eq!(endpoint.parameters().to_query_string(), "");
+    }
+
+    #[test]
+    fn watchlist_by_name() {
+        let endpoint = Watchlist::builder()
+            .watchlist(WatchlistSelector::name("my tech"))
+            .build()
+            .unwrap();
+
+        assert_eq!(endpoint.endpoint(), "watchlists:by_name");
+        assert_eq!(endpoint.parameters().to_query_string(), "name=my+tech");
+    }
+
+    #[test]
+    fn update_watchlist_requires_a_change() {
+        let err = UpdateWatchlist::builder()
+            .watchlist(WatchlistSelector::id("1"))
+            .build()
+            .unwrap_err();
+
+        assert_eq!(err.to_string(), "at least one of `name` or `symbols` must be given");
+    }
+
+    #[test]
+    fn update_watchlist_body() {
+        let endpoint = UpdateWatchlist::builder()
+            .watchlist(WatchlistSelector::name("tech"))
+            .name("big tech")
+            .build()
+            .unwrap();
+        assert_eq!(endpoint.parameters().to_query_string(), "name=tech");
+        assert_eq!(body(&endpoint), json!({"name": "big tech"}));
+
+        let endpoint = UpdateWatchlist::builder()
+            .watchlist(WatchlistSelector::id("1"))
+            .symbols(std::iter::empty::<String>())
+            .build()
+            .unwrap();
+        assert_eq!(body(&endpoint), json!({"symbols": []}));
+    }
+
+    #[test]
+    fn add_to_watchlist() {
+        let endpoint = AddToWatchlist::builder()
+            .watchlist(WatchlistSelector::name("tech"))
+            .symbol("AAPL")
+            .build()
+            .unwrap();
+
+        assert_eq!(endpoint.endpoint(), "watchlists:by_name");
+        assert_eq!(endpoint.parameters().to_query_string(), "name=tech");
+        assert_eq!(body(&endpoint), json!({"symbol": "AAPL"}));
+    }
+
+    #[test]
+    fn remove_from_watchlist() {
+        let endpoint = RemoveFromWatchlist::builder()
+            .watchlist(WatchlistSelector::id("1"))
+            .symbol("AAPL")
+            .build()
+            .unwrap();
+        assert_eq!(endpoint.endpoint(), "watchlists/1/AAPL");
+
+        let endpoint = RemoveFromWatchlist::builder()
+            .watchlist(WatchlistSelector::name("tech"))
+            .symbol("AAPL")
+            .build()
+            .unwrap();
+        assert_eq!(endpoint.endpoint(), "watchlists:by_name/AAPL");
+        assert_eq!(endpoint.parameters().to_query_string(), "name=tech");
+        assert!(endpoint.body().unwrap().is_none());
+    }
+
+    #[test]
+    fn watchlist_path_segments_are_escaped() {
+        let endpoint = RemoveFromWatchlist::builder()
+            .watchlist(WatchlistSelector::id("a/b c"))
+            .symbol("BTC/USD")
+            .build()
+            .unwrap();
+        assert_eq!(endpoint.endpoint(), "watchlists/a%2Fb%20c/BTC%2FUSD");
+
+        let endpoint = RemoveFromWatchlist::builder()
+            .watchlist(WatchlistSelector::name("crypto"))
+            .symbol("BTC/USD")
+            .build()
+            .unwrap();
+        assert_eq!(endpoint.endpoint(), "watchlists:by_name/BTC%2FUSD");
+    }
+}